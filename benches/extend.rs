@@ -0,0 +1,76 @@
+//! Compares `Extend` for `TrustedLen` iterators against the generic loop.
+//! Run with `cargo bench --features nightly`.
+#![feature(test, const_generics)]
+
+extern crate test;
+
+use arrayvec::ArrayVec;
+use test::{black_box, Bencher};
+
+const CAP: usize = 512;
+
+/// Forwards to the inner iterator, but doesn't implement `TrustedLen`,
+/// so extending from it takes the generic loop.
+struct Opaque<I>(I);
+
+impl <I: Iterator> Iterator for Opaque<I> {
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+#[bench]
+fn extend_trusted_len(b: &mut Bencher) {
+    let mut v = ArrayVec::<u32, {CAP}>::new();
+
+    b.iter(|| {
+        v.clear();
+        v.extend(0..black_box(CAP as u32));
+        black_box(&v);
+    });
+    b.bytes = (CAP * 4) as u64;
+}
+
+#[bench]
+fn extend_loop(b: &mut Bencher) {
+    let mut v = ArrayVec::<u32, {CAP}>::new();
+
+    b.iter(|| {
+        v.clear();
+        v.extend(Opaque(0..black_box(CAP as u32)));
+        black_box(&v);
+    });
+    b.bytes = (CAP * 4) as u64;
+}
+
+#[bench]
+fn extend_trusted_len_saturating(b: &mut Bencher) {
+    let mut v = ArrayVec::<u32, {CAP}>::new();
+
+    b.iter(|| {
+        v.clear();
+        v.extend(0..);
+        black_box(&v);
+    });
+    b.bytes = (CAP * 4) as u64;
+}
+
+#[bench]
+fn extend_loop_saturating(b: &mut Bencher) {
+    let mut v = ArrayVec::<u32, {CAP}>::new();
+
+    b.iter(|| {
+        v.clear();
+        v.extend(Opaque(0..));
+        black_box(&v);
+    });
+    b.bytes = (CAP * 4) as u64;
+}
@@ -0,0 +1,93 @@
+use crate::ArrayVec;
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+use core::mem;
+
+/// Reads a length in `0..=N`, followed by that many elements.
+impl <'a, T: Arbitrary<'a>, const N: usize> Arbitrary<'a> for ArrayVec<T, {N}> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let len = u.int_in_range(0..=N)?;
+        let mut array = Self::new();
+
+        for _ in 0..len {
+            // `len` never exceeds the capacity.
+            unsafe { array.push_unchecked(T::arbitrary(u)?) }
+        }
+
+        Ok(array)
+    }
+
+    /// Without any data the `ArrayVec` is empty, so the lower bound is always 0.
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        // `int_in_range` reads one byte at a time, until the rest of `N` is zero.
+        let mut len_bytes = 0;
+        while len_bytes < mem::size_of::<usize>() && N >> (len_bytes * 8) != 0 {
+            len_bytes += 1;
+        }
+        let elements = T::size_hint(depth).1.and_then(|max| max.checked_mul(N));
+        (0, elements.and_then(|max| max.checked_add(len_bytes)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arbitrary_never_exceeds_capacity() {
+        let bytes: ArrayVec<u8, {255}> = (0..255).map(|n: u8| n.wrapping_mul(167)).collect();
+
+        for start in 0..bytes.len() {
+            let mut u = Unstructured::new(&bytes[start..]);
+
+            let v = ArrayVec::<u16, {4}>::arbitrary(&mut u).unwrap();
+            assert!(v.len() <= 4);
+        }
+
+        let mut u = Unstructured::new(&[3, 1, 0, 2, 0, 3, 0]);
+        let v = ArrayVec::<u16, {4}>::arbitrary(&mut u).unwrap();
+        assert!(v.len() <= 4);
+    }
+
+    #[test]
+    fn arbitrary_reads_len_then_elements() {
+        let mut u = Unstructured::new(&[3, 10, 20, 30, 40]);
+        let v = ArrayVec::<u8, {4}>::arbitrary(&mut u).unwrap();
+        assert_eq!(&*v, &[10, 20, 30]);
+        assert_eq!(u.len(), 1);
+
+        // The length wraps around into `0..=N`.
+        let mut u = Unstructured::new(&[7, 10, 20, 30]);
+        let v = ArrayVec::<u8, {4}>::arbitrary(&mut u).unwrap();
+        assert_eq!(&*v, &[10, 20]);
+        assert_eq!(u.len(), 1);
+
+        // Only one length is possible, so none is read.
+        let mut u = Unstructured::new(&[10, 20]);
+        let v = ArrayVec::<u8, {0}>::arbitrary(&mut u).unwrap();
+        assert!(v.is_empty());
+        assert_eq!(u.len(), 2);
+    }
+
+    #[test]
+    fn arbitrary_exhausted() {
+        let mut u = Unstructured::new(&[]);
+        let v = ArrayVec::<u16, {4}>::arbitrary(&mut u).unwrap();
+        assert!(v.is_empty());
+
+        // Elements read past the end of the data are zero.
+        let mut u = Unstructured::new(&[2, 5]);
+        let v = ArrayVec::<u8, {4}>::arbitrary(&mut u).unwrap();
+        assert_eq!(&*v, &[5, 0]);
+        assert!(u.is_empty());
+    }
+
+    #[test]
+    fn arbitrary_size_hint() {
+        assert_eq!(ArrayVec::<u8, {0}>::size_hint(0), (0, Some(0)));
+        assert_eq!(ArrayVec::<u8, {4}>::size_hint(0), (0, Some(1 + 4)));
+        assert_eq!(ArrayVec::<u16, {255}>::size_hint(0), (0, Some(1 + 2 * 255)));
+        assert_eq!(ArrayVec::<u16, {256}>::size_hint(0), (0, Some(2 + 2 * 256)));
+        assert_eq!(ArrayVec::<&str, {4}>::size_hint(0), (0, None));
+    }
+}
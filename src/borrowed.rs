@@ -0,0 +1,167 @@
+use crate::error::CapacityError;
+
+use core::{
+    mem::MaybeUninit,
+    ops::{Deref, DerefMut},
+    ptr,
+    slice,
+};
+
+/// A vector like [`ArrayVec`](crate::ArrayVec), that borrows its backing array
+/// instead of owning it.
+///
+/// This allows reusing one large buffer, e.g. a `static mut` or a long-lived stack array,
+/// without moving it around. The buffer is empty again once the `BorrowedArrayVec` is dropped:
+/// the elements are dropped along with it.
+pub struct BorrowedArrayVec<'a, T, const N: usize> {
+    array: &'a mut [MaybeUninit<T>; N],
+    len: usize,
+}
+
+impl <'a, T, const N: usize> BorrowedArrayVec<'a, T, {N}> {
+    /// Creates a new, empty `BorrowedArrayVec` on top of `array`.
+    /// Any values already in `array` are considered uninitialized, and are never dropped.
+    #[inline]
+    pub fn new(array: &'a mut [MaybeUninit<T>; N]) -> Self {
+        Self { array, len: 0 }
+    }
+
+    /// Returns the number of elements in the `BorrowedArrayVec`.
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the `BorrowedArrayVec` is empty.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of elements the `BorrowedArrayVec` can hold.
+    #[inline(always)]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Push `item` onto the `BorrowedArrayVec`.
+    ///
+    /// # Panics
+    /// Panics if the `BorrowedArrayVec` is full.
+    #[inline]
+    pub fn push(&mut self, item: T) {
+        self.try_push(item).unwrap()
+    }
+
+    /// Tries to push `item` onto the `BorrowedArrayVec`.
+    /// A [`CapacityError`] is returned when there wasn't enough
+    /// free space for another item.
+    #[inline]
+    pub fn try_push(&mut self, item: T) -> Result<(), CapacityError<T>> {
+        if self.len < N {
+            self.array[self.len] = MaybeUninit::new(item);
+            self.len += 1;
+            Ok(())
+        } else {
+            Err(CapacityError::new(item))
+        }
+    }
+
+    /// Removes the last element and returns it, or `None` if the `BorrowedArrayVec` is empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            None
+        } else {
+            self.len -= 1;
+            unsafe { Some(ptr::read(self.array[self.len].as_ptr())) }
+        }
+    }
+
+    /// Drops all elements.
+    #[inline]
+    pub fn clear(&mut self) {
+        let len = self.len;
+        // Set the length first, so a panicking destructor doesn't cause a double drop.
+        self.len = 0;
+
+        unsafe {
+            let elements: *mut [T] = ptr::slice_from_raw_parts_mut(self.array.as_mut_ptr() as *mut T, len);
+            ptr::drop_in_place(elements);
+        }
+    }
+}
+
+impl <'a, T, const N: usize> Drop for BorrowedArrayVec<'a, T, {N}> {
+    fn drop(&mut self) {
+        self.clear()
+    }
+}
+
+impl <'a, T, const N: usize> Deref for BorrowedArrayVec<'a, T, {N}> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        unsafe { slice::from_raw_parts(self.array.as_ptr() as *const T, self.len) }
+    }
+}
+
+impl <'a, T, const N: usize> DerefMut for BorrowedArrayVec<'a, T, {N}> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { slice::from_raw_parts_mut(self.array.as_mut_ptr() as *mut T, self.len) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{Counted, DropCounter};
+    use std::string::{String, ToString};
+
+    #[test]
+    fn push_pop() {
+        let mut buffer: [MaybeUninit<String>; 3] = unsafe { MaybeUninit::uninit().assume_init() };
+
+        {
+            let mut v = BorrowedArrayVec::new(&mut buffer);
+            v.push(String::from("a"));
+            v.push(String::from("b"));
+            v[1].push('!');
+
+            assert_eq!(&*v, &["a", "b!"]);
+            assert_eq!(v.pop().as_deref(), Some("b!"));
+
+            v.push(String::from("c"));
+            v.push(String::from("d"));
+            assert_eq!(v.try_push(String::from("e")).unwrap_err().into_inner(), "e");
+        }
+
+        // The buffer can be reused once the first `BorrowedArrayVec` is gone.
+        let mut v = BorrowedArrayVec::new(&mut buffer);
+        assert!(v.is_empty());
+
+        for n in 0..3 {
+            v.push(n.to_string());
+        }
+
+        assert_eq!(&*v, &["0", "1", "2"]);
+    }
+
+    #[test]
+    fn drops_live_elements() {
+        let drops = DropCounter::new();
+        let mut buffer: [MaybeUninit<Counted>; 4] = unsafe { MaybeUninit::uninit().assume_init() };
+
+        let mut v = BorrowedArrayVec::new(&mut buffer);
+        v.push(drops.counted(0));
+        v.push(drops.counted(1));
+        v.push(drops.counted(2));
+        drop(v.pop());
+        assert_eq!(&*drops.order(), &[2]);
+
+        drop(v);
+        assert!(drops.all_dropped_once(3));
+    }
+}
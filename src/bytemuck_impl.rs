@@ -0,0 +1,69 @@
+//! Zero-copy helpers built on `bytemuck`.
+//!
+//! `ArrayVec` itself is never `Pod` or `Zeroable`:
+//! it carries a `len` field, and the slots past `len` may be uninitialized.
+//! Casting the whole struct would read those uninitialized bytes,
+//! and a cast *into* an `ArrayVec` could produce a `len` larger than the capacity.
+//! Therefore only the live slice is ever reinterpreted.
+
+use crate::ArrayVec;
+
+use core::mem::MaybeUninit;
+
+use bytemuck::{Pod, PodCastError, Zeroable};
+
+impl <T, const N: usize> ArrayVec<T, {N}> {
+    /// Returns a full `ArrayVec`, where every element is zero-initialized.
+    #[inline]
+    pub fn zeroed() -> Self
+    where
+        T: Zeroable
+    {
+        Self {
+            // All-zero bytes are a valid `T`, because `T: Zeroable`.
+            array: unsafe { MaybeUninit::<[MaybeUninit<T>; N]>::zeroed().assume_init() },
+            len: N,
+        }
+    }
+
+    /// Tries to reinterpret the live elements as a slice of `U`.
+    /// See [`bytemuck::try_cast_slice`] for the possible errors.
+    #[inline]
+    pub fn try_cast_slice<U: Pod>(&self) -> Result<&[U], PodCastError>
+    where
+        T: Pod
+    {
+        bytemuck::try_cast_slice(self)
+    }
+
+    /// Tries to reinterpret the live elements as a mutable slice of `U`.
+    /// See [`bytemuck::try_cast_slice_mut`] for the possible errors.
+    #[inline]
+    pub fn try_cast_slice_mut<U: Pod>(&mut self) -> Result<&mut [U], PodCastError>
+    where
+        T: Pod
+    {
+        bytemuck::try_cast_slice_mut(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zeroed() {
+        let mut v = ArrayVec::<u32, {4}>::zeroed();
+
+        assert!(v.is_full());
+        assert_eq!(&*v, &[0, 0, 0, 0]);
+
+        v[1] = 0x0101_0101;
+        let bytes: &[u8] = v.try_cast_slice().unwrap();
+        assert_eq!(bytes.len(), 16);
+        assert_eq!(&bytes[4..8], &[1, 1, 1, 1]);
+
+        v.pop();
+        assert_eq!(v.try_cast_slice::<u8>().unwrap().len(), 12);
+    }
+}
@@ -0,0 +1,445 @@
+use crate::{index, ArrayVec};
+
+use core::{
+    iter::FusedIterator,
+    marker::PhantomData,
+    mem::ManuallyDrop,
+    ops::RangeBounds,
+    ptr::{self, NonNull},
+    slice,
+};
+
+impl <T, const N: usize> ArrayVec<T, {N}> {
+    /// Removes the elements in `range` from the `ArrayVec`,
+    /// and returns them as an iterator.
+    ///
+    /// When the [`Drain`] is dropped, any elements in `range` that were not yielded
+    /// are dropped, and the elements after `range` are shifted down to close the gap.
+    /// Use [`Drain::keep_rest`] to keep the unyielded elements instead.
+    ///
+    /// # Panics
+    /// Panics if the start of `range` is greater than the end,
+    /// or if the end of `range` is greater than the length of the `ArrayVec`.
+    pub fn drain<R>(&mut self, range: R) -> Drain<'_, T, {N}>
+    where
+        R: RangeBounds<usize>
+    {
+        let len = self.len();
+        let (start, end) = index::range_bounds(&range, len, N);
+
+        unsafe {
+            // If the `Drain` is leaked, the drained range and the tail are leaked too,
+            // but nothing is dropped twice.
+            self.set_len(start);
+        }
+
+        Drain {
+            index: start,
+            end,
+            tail_start: end,
+            tail_len: len - end,
+            vec: NonNull::from(self),
+            marker: PhantomData,
+        }
+    }
+
+    /// Removes all elements from the `ArrayVec`, and returns them as an iterator.
+    ///
+    /// This is the same as `drain(..)`: the `ArrayVec` is empty afterwards, and stays usable.
+    /// If the [`Drain`] is dropped early, the elements that were not yielded are dropped.
+    /// Use [`ArrayVec::take`] to move all elements into a new `ArrayVec` instead.
+    #[inline]
+    pub fn drain_all(&mut self) -> Drain<'_, T, {N}> {
+        self.drain(..)
+    }
+
+    /// Drops the elements in `range`, and shifts the elements after `range` down to close the gap.
+    ///
+    /// This is the same as dropping the [`Drain`] returned by [`drain`](ArrayVec::drain) right away.
+    /// If dropping an element panics, the remaining elements in `range` are dropped,
+    /// and the tail is still shifted down.
+    ///
+    /// # Panics
+    /// Panics if the start of `range` is greater than the end,
+    /// or if the end of `range` is greater than the length of the `ArrayVec`.
+    #[inline]
+    pub fn remove_range<R>(&mut self, range: R)
+    where
+        R: RangeBounds<usize>
+    {
+        self.drain(range);
+    }
+
+    /// Replaces the elements in `range` with the elements of `replace_with`,
+    /// and returns the removed elements as an iterator.
+    ///
+    /// The replacement happens when the [`Splice`] is dropped,
+    /// removing any elements in `range` that were not yielded.
+    ///
+    /// # Panics
+    /// Panics if the start of `range` is greater than the end,
+    /// or if the end of `range` is greater than the length of the `ArrayVec`.
+    /// When the `Splice` is dropped, it panics if the resulting length would exceed the capacity.
+    /// In that case the `ArrayVec` holds as many replacement elements as fit, followed by the tail.
+    ///
+    /// If the `Splice` is dropped while the thread is already panicking, the elements that don't fit
+    /// are left in `replace_with` instead. This needs the `std` feature: without it, the thread can't
+    /// tell that it is unwinding, and the second panic aborts the process.
+    pub fn splice<R, I>(&mut self, range: R, replace_with: I) -> Splice<'_, I::IntoIter, {N}>
+    where
+        R: RangeBounds<usize>,
+        I: IntoIterator<Item = T>
+    {
+        Splice {
+            drain: self.drain(range),
+            replace_with: replace_with.into_iter(),
+        }
+    }
+}
+
+/// A draining iterator over a range of an [`ArrayVec`].
+///
+/// This struct is created by [`ArrayVec::drain`].
+///
+/// A `Drain` can be consumed in two ways:
+/// - Dropping it drops the elements that were not yielded,
+///   and shifts the elements after the drained range down to close the gap.
+/// - [`Drain::keep_rest`] keeps the elements that were not yielded in the `ArrayVec`,
+///   followed by the elements after the drained range.
+pub struct Drain<'a, T, const N: usize> {
+    /// The elements at `index..end` are not yielded yet.
+    index: usize,
+    end: usize,
+    /// Index of the first element after the drained range.
+    tail_start: usize,
+    /// Number of elements after the drained range.
+    tail_len: usize,
+    vec: NonNull<ArrayVec<T, {N}>>,
+    marker: PhantomData<&'a mut ArrayVec<T, {N}>>,
+}
+
+impl <'a, T, const N: usize> Drain<'a, T, {N}> {
+    /// Returns the elements that are not yielded yet as a slice.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.first_ptr().add(self.index), self.end - self.index) }
+    }
+
+    /// Keeps the elements that were not yielded in the `ArrayVec`,
+    /// instead of dropping them.
+    pub fn keep_rest(self) {
+        let mut this = ManuallyDrop::new(self);
+
+        unsafe {
+            let vec = this.vec.as_mut();
+            let start = vec.len();
+            let first_ptr: *mut T = vec.array.as_mut_ptr() as *mut T;
+
+            let unyielded_len = this.end - this.index;
+
+            let dst = first_ptr.add(start);
+            ptr::copy(first_ptr.add(this.index), dst, unyielded_len);
+            ptr::copy(first_ptr.add(this.tail_start), dst.add(unyielded_len), this.tail_len);
+
+            vec.set_len(start + unyielded_len + this.tail_len);
+        }
+    }
+
+    #[inline(always)]
+    fn first_ptr(&self) -> *const T {
+        unsafe { self.vec.as_ref().array.as_ptr() as *const T }
+    }
+
+    /// Shifts the tail down to directly after the live elements of the `ArrayVec`.
+    unsafe fn move_tail(&mut self) {
+        let vec = self.vec.as_mut();
+        let start = vec.len();
+        let first_ptr: *mut T = vec.array.as_mut_ptr() as *mut T;
+
+        ptr::copy(first_ptr.add(self.tail_start), first_ptr.add(start), self.tail_len);
+        vec.set_len(start + self.tail_len);
+    }
+}
+
+impl <'a, T, const N: usize> Iterator for Drain<'a, T, {N}> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index == self.end {
+            None
+        } else {
+            unsafe {
+                let elem = ptr::read(self.first_ptr().add(self.index));
+                self.index += 1;
+                Some(elem)
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.index;
+        (len, Some(len))
+    }
+}
+
+impl <'a, T, const N: usize> DoubleEndedIterator for Drain<'a, T, {N}> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index == self.end {
+            None
+        } else {
+            unsafe {
+                self.end -= 1;
+                Some(ptr::read(self.first_ptr().add(self.end)))
+            }
+        }
+    }
+}
+
+impl <'a, T, const N: usize> ExactSizeIterator for Drain<'a, T, {N}> {}
+impl <'a, T, const N: usize> FusedIterator for Drain<'a, T, {N}> {}
+
+impl <'a, T, const N: usize> Drop for Drain<'a, T, {N}> {
+    fn drop(&mut self) {
+        /// Moves the tail back, even if dropping an element panics.
+        struct DropGuard<'r, 'a, T, const N: usize>(&'r mut Drain<'a, T, {N}>);
+
+        impl <'r, 'a, T, const N: usize> Drop for DropGuard<'r, 'a, T, {N}> {
+            fn drop(&mut self) {
+                unsafe { self.0.move_tail() }
+            }
+        }
+
+        let index = self.index;
+        let len = self.end - index;
+        self.index = self.end;
+
+        let guard = DropGuard(self);
+
+        unsafe {
+            let first_ptr: *mut T = guard.0.vec.as_mut().array.as_mut_ptr() as *mut T;
+            let unyielded: *mut [T] = ptr::slice_from_raw_parts_mut(first_ptr.add(index), len);
+            ptr::drop_in_place(unyielded);
+        }
+    }
+}
+
+/// A splicing iterator for an [`ArrayVec`].
+///
+/// This struct is created by [`ArrayVec::splice`].
+pub struct Splice<'a, I: Iterator, const N: usize> {
+    drain: Drain<'a, I::Item, {N}>,
+    replace_with: I,
+}
+
+impl <'a, I: Iterator, const N: usize> Iterator for Splice<'a, I, {N}> {
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.drain.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.drain.size_hint()
+    }
+}
+
+impl <'a, I: Iterator, const N: usize> DoubleEndedIterator for Splice<'a, I, {N}> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.drain.next_back()
+    }
+}
+
+impl <'a, I: Iterator, const N: usize> ExactSizeIterator for Splice<'a, I, {N}> {}
+
+impl <'a, I: Iterator, const N: usize> Drop for Splice<'a, I, {N}> {
+    fn drop(&mut self) {
+        self.drain.by_ref().for_each(drop);
+
+        unsafe {
+            // Move the tail to the end of the backing array,
+            // so the replacement elements can be written into the gap.
+            // Dropping `self.drain` moves the tail back, closing the gap.
+            let vec = self.drain.vec.as_mut();
+            let first_ptr: *mut I::Item = vec.array.as_mut_ptr() as *mut I::Item;
+            let tail_len = self.drain.tail_len;
+            let tail_start = N - tail_len;
+
+            ptr::copy(first_ptr.add(self.drain.tail_start), first_ptr.add(tail_start), tail_len);
+            self.drain.tail_start = tail_start;
+
+            while vec.len() < tail_start {
+                match self.replace_with.next() {
+                    Some(item) => vec.push_unchecked(item),
+                    None => return,
+                }
+            }
+        }
+
+        if self.replace_with.next().is_some() && !panicking() {
+            panic!("splice: the replacement elements exceed the capacity of {}", N);
+        }
+    }
+}
+
+/// Returns whether the thread is unwinding, in which case panicking again aborts.
+/// Without the `std` feature this can't be known, and `false` is returned.
+#[inline]
+fn panicking() -> bool {
+    #[cfg(feature = "std")]
+    {
+        std::thread::panicking()
+    }
+
+    #[cfg(not(feature = "std"))]
+    {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{Counted, DropCounter};
+    use std::string::{String, ToString};
+
+    #[test]
+    fn drain_drops_and_shifts() {
+        let mut v: ArrayVec<String, {8}> = (0..6).map(|n| n.to_string()).collect();
+
+        {
+            let mut drain = v.drain(1..4);
+            assert_eq!(drain.len(), 3);
+            assert_eq!(drain.next().as_deref(), Some("1"));
+            assert_eq!(drain.next_back().as_deref(), Some("3"));
+        }
+
+        assert_eq!(&*v, &["0", "4", "5"]);
+
+        let drained: ArrayVec<String, {8}> = v.drain(..).collect();
+        assert_eq!(&*drained, &["0", "4", "5"]);
+        assert!(v.is_empty());
+    }
+
+    #[test]
+    fn drain_keep_rest() {
+        let mut v: ArrayVec<String, {8}> = (0..6).map(|n| n.to_string()).collect();
+
+        let mut drain = v.drain(1..=4);
+        assert_eq!(drain.next().as_deref(), Some("1"));
+        assert_eq!(drain.as_slice(), &["2", "3", "4"]);
+        drain.keep_rest();
+
+        assert_eq!(&*v, &["0", "2", "3", "4", "5"]);
+
+        v.drain(2..2).keep_rest();
+        assert_eq!(v.len(), 5);
+    }
+
+    #[test]
+    fn splice() {
+        let mut v: ArrayVec<i32, {5}> = [1, 2, 3, 4].iter().copied().collect();
+
+        let removed: ArrayVec<i32, {5}> = v.splice(1..3, [9, 9, 9].iter().copied()).collect();
+        assert_eq!(&*removed, &[2, 3]);
+        assert_eq!(&*v, &[1, 9, 9, 9, 4]);
+
+        v.splice(..4, Some(7));
+        assert_eq!(&*v, &[7, 4]);
+
+        v.splice(2.., 0..3);
+        assert_eq!(&*v, &[7, 4, 0, 1, 2]);
+    }
+
+    #[test]
+    fn splice_overflow() {
+        use std::panic::{self, AssertUnwindSafe};
+
+        let mut v: ArrayVec<String, {4}> = (0..3).map(|n| n.to_string()).collect();
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            v.splice(1..2, (5..8).map(|n| n.to_string()));
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(&*v, &["0", "5", "6", "2"]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn splice_overflow_while_unwinding() {
+        use std::panic::{self, AssertUnwindSafe};
+
+        let mut v: ArrayVec<String, {4}> = (0..3).map(|n| n.to_string()).collect();
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            let _splice = v.splice(1..2, (5..8).map(|n| n.to_string()));
+            panic!("boom");
+        }));
+
+        // The `Splice` didn't panic a second time, which would have aborted.
+        assert_eq!(result.unwrap_err().downcast_ref::<&str>(), Some(&"boom"));
+        assert_eq!(&*v, &["0", "5", "6", "2"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "range bound overflows usize")]
+    fn drain_inclusive_usize_max() {
+        let mut v: ArrayVec<u8, {8}> = (0..4).collect();
+        v.drain(..=usize::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "range end 5 out of range for ArrayVec of len 4 (capacity 8)")]
+    fn drain_out_of_range() {
+        let mut v: ArrayVec<u8, {8}> = (0..4).collect();
+        v.drain(2..5);
+    }
+
+    #[test]
+    fn remove_range() {
+        let mut v: ArrayVec<i32, {4}> = [1, 2, 3, 4].iter().copied().collect();
+        v.remove_range(1..3);
+        assert_eq!(&*v, &[1, 4]);
+
+        v.remove_range(..);
+        assert!(v.is_empty());
+    }
+
+    #[test]
+    fn remove_range_panic_safety() {
+        use std::panic::{self, AssertUnwindSafe};
+
+        let drops = DropCounter::new();
+        let mut v: ArrayVec<Counted, {5}> =
+            (0..5).map(|n| if n == 1 { drops.bomb(n) } else { drops.counted(n) }).collect();
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| v.remove_range(1..4)));
+
+        assert!(result.is_err());
+        assert_eq!(&*drops.order(), &[1, 2, 3]);
+        assert_eq!(v.iter().map(|c| c.id).collect::<ArrayVec<usize, {5}>>().as_slice(), &[0, 4]);
+    }
+
+    #[test]
+    fn drain_all() {
+        let drops = DropCounter::new();
+        let mut v: ArrayVec<Counted, {4}> = (0..4).map(|n| drops.counted(n)).collect();
+
+        let mut drain = v.drain_all();
+        drop(drain.next());
+        assert_eq!(drops.total(), 1);
+        drop(drain);
+
+        assert!(drops.all_dropped_once(4));
+        assert!(v.is_empty());
+
+        v.push(drops.counted(4));
+        assert_eq!(v.len(), 1);
+    }
+}
@@ -1,6 +1,6 @@
 
 use core::{
-    fmt::{self, Debug},
+    fmt::{self, Debug, Display},
 };
 
 pub struct CapacityError<T = ()>(T);
@@ -10,6 +10,31 @@ impl <T> CapacityError<T> {
     pub const fn new(item: T) -> Self {
         Self(item)
     }
+
+    /// Returns a reference to the element that didn't fit.
+    #[inline]
+    pub const fn element(&self) -> &T {
+        &self.0
+    }
+
+    /// Returns the element that didn't fit.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+
+    /// Returns a value that debug-formats the error including the element that didn't fit,
+    /// e.g. `CapacityError { element: 42 }`.
+    ///
+    /// The `Debug` impl of `CapacityError` itself can't show the element,
+    /// since it is implemented for every `T`, including those that aren't `Debug`.
+    #[inline]
+    pub fn debug_element(&self) -> impl Debug + '_
+    where
+        T: Debug
+    {
+        DebugElement(&self.0)
+    }
 }
 
 impl <T> Debug for CapacityError<T> {
@@ -18,3 +43,149 @@ impl <T> Debug for CapacityError<T> {
     }
 }
 
+struct DebugElement<'a, T>(&'a T);
+
+impl <'a, T: Debug> Debug for DebugElement<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CapacityError")
+            .field("element", self.0)
+            .finish()
+    }
+}
+
+impl <T> Display for CapacityError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad("insufficient capacity")
+    }
+}
+
+#[cfg(feature = "std")]
+impl <T> std::error::Error for CapacityError<T> {}
+
+/// The error returned when an index is out of bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndexOutOfBounds {
+    index: usize,
+    len: usize,
+}
+
+impl IndexOutOfBounds {
+    #[inline]
+    pub const fn new(index: usize, len: usize) -> Self {
+        Self { index, len }
+    }
+
+    /// Returns the index that was out of bounds.
+    #[inline]
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the length of the `ArrayVec` at the time of the error.
+    #[inline]
+    pub const fn vec_len(&self) -> usize {
+        self.len
+    }
+}
+
+impl Display for IndexOutOfBounds {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "index {} out of range for ArrayVec of len {}", self.index, self.len)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for IndexOutOfBounds {}
+
+/// The error returned by [`ArrayVec::try_insert_checked`](crate::ArrayVec::try_insert_checked).
+/// Both variants hold the element that was not inserted.
+pub enum InsertError<T> {
+    /// The index was greater than the length.
+    OutOfBounds(T),
+    /// The `ArrayVec` was full.
+    CapacityExceeded(T),
+}
+
+impl <T> InsertError<T> {
+    /// Returns the element that was not inserted.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        match self {
+            Self::OutOfBounds(item) | Self::CapacityExceeded(item) => item,
+        }
+    }
+}
+
+impl <T> Debug for InsertError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::OutOfBounds(_) => f.pad("OutOfBounds(..)"),
+            Self::CapacityExceeded(_) => f.pad("CapacityExceeded(..)"),
+        }
+    }
+}
+
+impl <T> Display for InsertError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::OutOfBounds(_) => f.pad("insertion index out of bounds"),
+            Self::CapacityExceeded(_) => f.pad("insufficient capacity"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl <T> std::error::Error for InsertError<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ArrayVec;
+    use std::{format, string::{String, ToString}};
+
+    #[test]
+    fn display() {
+        let mut v = ArrayVec::<u8, {0}>::new();
+        assert_eq!(v.try_push(1).unwrap_err().to_string(), "insufficient capacity");
+    }
+
+    #[test]
+    fn recover_element() {
+        let mut v = ArrayVec::<String, {1}>::new();
+        v.push(String::from("first"));
+
+        let err = v.try_push(String::from("second")).unwrap_err();
+        assert_eq!(err.element(), "second");
+
+        let mut other = ArrayVec::<String, {1}>::new();
+        other.push(err.into_inner());
+        assert_eq!(&*other, &["second"]);
+    }
+
+    #[test]
+    fn debug() {
+        let err = CapacityError::new(42);
+
+        assert_eq!(format!("{:?}", err), "CapacityError { .. }");
+        assert_eq!(format!("{:?}", err.debug_element()), "CapacityError { element: 42 }");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn propagate_as_boxed_error() {
+        use std::{boxed::Box, error::Error};
+
+        fn fill(v: &mut ArrayVec<u8, {2}>) -> Result<(), Box<dyn Error>> {
+            for n in 0..3 {
+                v.try_push(n)?;
+            }
+            Ok(())
+        }
+
+        let mut v = ArrayVec::new();
+        let err = fill(&mut v).unwrap_err();
+
+        assert_eq!(err.to_string(), "insufficient capacity");
+        assert_eq!(&*v, &[0, 1]);
+    }
+}
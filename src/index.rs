@@ -0,0 +1,223 @@
+use crate::ArrayVec;
+
+use core::{
+    ops::{
+        Bound, Index, IndexMut, RangeBounds,
+        Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive,
+    },
+    slice::SliceIndex,
+};
+
+impl <T, const N: usize> ArrayVec<T, {N}> {
+    /// Returns a reference to the element or subslice at `index`,
+    /// or `None` if it is out of bounds, like the slice method.
+    /// Only the first [`ArrayVec::len`] elements are in bounds.
+    #[inline]
+    pub fn get<I>(&self, index: I) -> Option<&I::Output>
+    where
+        I: SliceIndex<[T]>
+    {
+        self.as_slice().get(index)
+    }
+
+    /// Returns a mutable reference to the element or subslice at `index`,
+    /// or `None` if it is out of bounds.
+    #[inline]
+    pub fn get_mut<I>(&mut self, index: I) -> Option<&mut I::Output>
+    where
+        I: SliceIndex<[T]>
+    {
+        self.as_mut_slice().get_mut(index)
+    }
+}
+
+impl <T, const N: usize> Index<usize> for ArrayVec<T, {N}> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        let len = self.len();
+
+        match self.as_slice().get(index) {
+            Some(element) => element,
+            None => index_out_of_range(index, len, N),
+        }
+    }
+}
+
+impl <T, const N: usize> IndexMut<usize> for ArrayVec<T, {N}> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        let len = self.len();
+
+        match self.as_mut_slice().get_mut(index) {
+            Some(element) => element,
+            None => index_out_of_range(index, len, N),
+        }
+    }
+}
+
+#[cold]
+#[inline(never)]
+fn index_out_of_range(index: usize, len: usize, capacity: usize) -> ! {
+    panic!("index {} out of range for ArrayVec of len {} (capacity {})", index, len, capacity)
+}
+
+/// Returns the start and end of `range`,
+/// panicking if they don't describe a valid range into `len` elements.
+pub(crate) fn range_bounds<R: RangeBounds<usize>>(range: &R, len: usize, capacity: usize) -> (usize, usize) {
+    let start = match range.start_bound() {
+        Bound::Included(&n) => n,
+        Bound::Excluded(&n) => n.checked_add(1).unwrap_or_else(|| range_overflow()),
+        Bound::Unbounded => 0,
+    };
+
+    let end = match range.end_bound() {
+        Bound::Included(&n) => n.checked_add(1).unwrap_or_else(|| range_overflow()),
+        Bound::Excluded(&n) => n,
+        Bound::Unbounded => len,
+    };
+
+    if start > end {
+        panic!("range starts at {} but ends at {}", start, end);
+    }
+
+    if end > len {
+        panic!("range end {} out of range for ArrayVec of len {} (capacity {})", end, len, capacity);
+    }
+
+    (start, end)
+}
+
+#[cold]
+#[inline(never)]
+fn range_overflow() -> ! {
+    panic!("range bound overflows usize")
+}
+
+macro_rules! impl_index_range {
+    ($($range:ty),*) => {
+        $(
+            impl <T, const N: usize> Index<$range> for ArrayVec<T, {N}> {
+                type Output = [T];
+
+                #[inline]
+                fn index(&self, range: $range) -> &[T] {
+                    let (start, end) = range_bounds(&range, self.len(), N);
+                    unsafe { self.as_slice().get_unchecked(start..end) }
+                }
+            }
+
+            impl <T, const N: usize> IndexMut<$range> for ArrayVec<T, {N}> {
+                #[inline]
+                fn index_mut(&mut self, range: $range) -> &mut [T] {
+                    let (start, end) = range_bounds(&range, self.len(), N);
+                    unsafe { self.as_mut_slice().get_unchecked_mut(start..end) }
+                }
+            }
+        )*
+    };
+}
+
+impl_index_range!(
+    Range<usize>,
+    RangeFrom<usize>,
+    RangeTo<usize>,
+    RangeInclusive<usize>,
+    RangeToInclusive<usize>,
+    (Bound<usize>, Bound<usize>)
+);
+
+impl <T, const N: usize> Index<RangeFull> for ArrayVec<T, {N}> {
+    type Output = [T];
+
+    #[inline(always)]
+    fn index(&self, _: RangeFull) -> &[T] {
+        self
+    }
+}
+
+impl <T, const N: usize> IndexMut<RangeFull> for ArrayVec<T, {N}> {
+    #[inline(always)]
+    fn index_mut(&mut self, _: RangeFull) -> &mut [T] {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn index() {
+        let mut v: ArrayVec<i32, {8}> = (0..5).collect();
+
+        v[0] = 10;
+        v[1..3].copy_from_slice(&[11, 12]);
+
+        assert_eq!(v[0], 10);
+        assert_eq!(&v[..2], &[10, 11]);
+        assert_eq!(&v[3..], &[3, 4]);
+        assert_eq!(&v[1..=2], &[11, 12]);
+        assert_eq!(&v[..=0], &[10]);
+        assert_eq!(&v[..], &[10, 11, 12, 3, 4]);
+        assert!(v[5..].is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "index 7 out of range for ArrayVec of len 3")]
+    fn index_out_of_range() {
+        let v: ArrayVec<i32, {8}> = (0..3).collect();
+        let _ = v[7];
+    }
+
+    #[test]
+    #[should_panic(expected = "range end 4 out of range for ArrayVec of len 3 (capacity 8)")]
+    fn range_out_of_range() {
+        let v: ArrayVec<i32, {8}> = (0..3).collect();
+        let _ = &v[1..4];
+    }
+
+    #[test]
+    #[should_panic(expected = "range starts at 2 but ends at 1")]
+    fn range_start_after_end() {
+        let v: ArrayVec<i32, {8}> = (0..3).collect();
+        let (start, end) = (2, 1);
+        let _ = &v[start..end];
+    }
+
+    #[test]
+    fn index_bounds() {
+        let mut v: ArrayVec<i32, {8}> = (0..5).collect();
+
+        assert_eq!(&v[(Bound::Excluded(0), Bound::Unbounded)], &[1, 2, 3, 4]);
+        assert_eq!(&v[(Bound::Included(1), Bound::Included(2))], &[1, 2]);
+        assert!(v[(Bound::Excluded(4), Bound::Excluded(5))].is_empty());
+
+        v[(Bound::Unbounded, Bound::Excluded(2))].copy_from_slice(&[10, 11]);
+        assert_eq!(&*v, &[10, 11, 2, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "range end 6 out of range for ArrayVec of len 5 (capacity 8)")]
+    fn index_bounds_out_of_range() {
+        let v: ArrayVec<i32, {8}> = (0..5).collect();
+        let _ = &v[(Bound::Excluded(1), Bound::Included(5))];
+    }
+
+    #[test]
+    fn get() {
+        let mut v: ArrayVec<i32, {8}> = (0..5).collect();
+
+        assert_eq!(v.get(0), Some(&0));
+        assert_eq!(v.get(10), None);
+        assert_eq!(v.get(5), None);
+        assert_eq!(v.get(1..3), Some(&[1, 2][..]));
+        assert_eq!(v.get(4..6), None);
+
+        if let Some(slice) = v.get_mut(3..) {
+            slice[0] = 9;
+        }
+        assert_eq!(&*v, &[0, 1, 2, 9, 4]);
+    }
+}
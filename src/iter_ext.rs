@@ -0,0 +1,46 @@
+use crate::{ArrayVec, error::CapacityError};
+
+/// Extension methods to collect an iterator into an [`ArrayVec`],
+/// with the capacity given as a const parameter.
+pub trait IteratorExt: Iterator + Sized {
+    /// Collects the elements into an `ArrayVec` with capacity `N`.
+    /// Like [`FromIterator`](core::iter::FromIterator), elements that don't fit are not taken from the iterator.
+    #[inline]
+    fn collect_arrayvec<const N: usize>(self) -> ArrayVec<Self::Item, {N}> {
+        self.collect()
+    }
+
+    /// Collects the elements into an `ArrayVec` with capacity `N`.
+    /// A [`CapacityError`] is returned if the iterator has more than `N` elements.
+    #[inline]
+    fn try_collect_arrayvec<const N: usize>(self) -> Result<ArrayVec<Self::Item, {N}>, CapacityError> {
+        let mut array = ArrayVec::new();
+        array.try_extend(self)?;
+        Ok(array)
+    }
+}
+
+impl <I: Iterator> IteratorExt for I {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collect_arrayvec() {
+        let v = (0..3).collect_arrayvec::<8>();
+        assert_eq!(&*v, &[0, 1, 2]);
+
+        let v = (0..10).collect_arrayvec::<4>();
+        assert_eq!(&*v, &[0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn try_collect_arrayvec() {
+        let v = (0..3).try_collect_arrayvec::<3>().unwrap();
+        assert_eq!(&*v, &[0, 1, 2]);
+
+        assert!((0..4).try_collect_arrayvec::<3>().is_err());
+        assert!((0..4).filter(|n| n % 2 == 0).try_collect_arrayvec::<2>().is_ok());
+    }
+}
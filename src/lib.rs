@@ -1,32 +1,89 @@
-//#![no_std]
+#![no_std]
 #![feature(const_generics)]
+#![cfg_attr(feature = "nightly", feature(min_specialization, trusted_len, const_evaluatable_checked))]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 pub mod error;
-use error::CapacityError;
+use error::{CapacityError, IndexOutOfBounds, InsertError};
+
+mod string;
+pub use string::ArrayString;
+
+mod drain;
+pub use drain::{Drain, Splice};
+
+mod windows;
+pub use windows::WindowsMut;
+
+mod borrowed;
+pub use borrowed::BorrowedArrayVec;
+
+mod iter_ext;
+pub use iter_ext::IteratorExt;
+
+mod index;
+mod sort;
 
-//mod string;
-//pub use string::ArrayString;
+#[cfg(feature = "nightly")]
+mod spec_extend;
+
+#[cfg(test)]
+pub(crate) mod test_util;
 mod macros;
 
+#[cfg(feature = "serde")]
+mod serde_impl;
+
+#[cfg(feature = "bytemuck")]
+mod bytemuck_impl;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impl;
+
 use core::{
+    borrow::{Borrow, BorrowMut},
+    cmp,
+    convert::TryInto,
+    fmt,
+    hash::{Hash, Hasher},
     mem::{self, MaybeUninit},
     ptr,
     slice,
     ops::{Deref, DerefMut},
-    iter::{FusedIterator, Extend, FromIterator},
+    iter::{self, FusedIterator, Extend, FromIterator},
 };
 
+/// A vector with a fixed capacity of `N` elements, stored inline.
+///
+/// The length is stored as a `usize`, whatever the capacity,
+/// so an `ArrayVec<u8, {4}>` takes up two words on 64-bit targets.
+/// Choosing a smaller length type from `N` can not be expressed with const generics yet;
+/// [`ArrayVec::INDEX_BYTES`] is the size of the smallest type that would do.
 pub struct ArrayVec<T, const N: usize> {
     array: [MaybeUninit<T>; N],
     len: usize,
 }
 
+const fn index_bytes(n: usize) -> usize {
+    if n <= u8::MAX as usize {
+        1
+    } else if n <= u16::MAX as usize {
+        2
+    } else if n <= u32::MAX as usize {
+        4
+    } else {
+        8
+    }
+}
+
 impl<T, const N: usize> Default for ArrayVec<T, { N }> {
     fn default() -> Self {
-        Self {
-            array: unsafe { MaybeUninit::<_>::uninit().assume_init() },
-            len: 0,
-        }
+        Self::new()
     }
 }
 
@@ -35,25 +92,71 @@ impl <T, const N: usize> ArrayVec<T, {N}> {
     /// and returns it.
     /// Marked unsafe, because it indexes into the array without
     /// bounds checks, and it assumes the element at `index` has been initialized.
-    unsafe fn take(&mut self, index: usize) -> T {
+    unsafe fn take_at(&mut self, index: usize) -> T {
         debug_assert!(index < self.len());
         let element = self.array.get_unchecked_mut(index);
         ptr::read(element.as_ptr())
     }
+
+    /// Writes `item` at `index`, without dropping the old value,
+    /// and returns a mutable reference to it.
+    /// Marked unsafe, because it indexes into the array without
+    /// bounds checks. It does not change the length.
+    unsafe fn write_at(&mut self, index: usize, item: T) -> &mut T {
+        debug_assert!(index < self.capacity());
+        let element = self.array.get_unchecked_mut(index);
+        ptr::write(element.as_mut_ptr(), item);
+        &mut *element.as_mut_ptr()
+    }
 }
 
 impl<T, const N: usize> ArrayVec<T, { N }> {
+    /// The number of elements the `ArrayVec` can hold.
+    pub const CAPACITY: usize = N;
+
+    /// The size in bytes of the smallest unsigned integer type that holds every length up to `N`:
+    /// 1 for a `u8`, 2 for a `u16`, 4 for a `u32`, and 8 for a `u64`.
+    pub const INDEX_BYTES: usize = index_bytes(N);
+
+    /// Creates a new, empty `ArrayVec`.
+    /// This can be used to initialize `const` and `static` items.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            array: unsafe { MaybeUninit::<_>::uninit().assume_init() },
+            len: 0,
+        }
+    }
+
+    /// Collects the `Ok` values of `iter` into a new `ArrayVec`.
+    /// Returns the first `Err` that is encountered,
+    /// dropping the values that were collected so far.
+    ///
+    /// Like [`FromIterator`], this stops when the `ArrayVec` is full:
+    /// items after the first `N` are not inspected, so errors among them are not reported.
+    pub fn try_from_results<E, I>(iter: I) -> Result<Self, E>
+    where
+        I: IntoIterator<Item = Result<T, E>>
+    {
+        let mut array = Self::new();
+
+        for item in iter.into_iter().take(N) {
+            unsafe { array.push_unchecked(item?) }
+        }
+
+        Ok(array)
+    }
+
     /// Returns whether the `ArrayVec` is empty.
     #[inline(always)]
     pub const fn is_empty(&self) -> bool {
         self.len() == 0
     }
 
-    constify! {
-        if #[cfg(not(miri))]
-        pub fn is_full(self: &Self) -> bool {
-            self.len() == self.capacity()
-        }    
+    /// Returns whether the `ArrayVec` is full.
+    #[inline(always)]
+    pub const fn is_full(&self) -> bool {
+        self.len() == self.capacity()
     }
 
     /// Returns the number of elements in the `ArrayVec`.
@@ -62,28 +165,59 @@ impl<T, const N: usize> ArrayVec<T, { N }> {
         self.len
     }
 
-    constify! {
-        if #[cfg(not(miri))]
-        pub fn capacity(self: &Self) -> usize {
-            #[cfg(not(miri))]
-            {
-                N
-            }
+    /// Returns the number of elements the `ArrayVec` can hold.
+    #[inline(always)]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
 
-            #[cfg(miri)]
-            {
-                self.array.len()
-            }
-        }
+    /// Returns the number of elements that can still be pushed onto the `ArrayVec`.
+    #[inline(always)]
+    pub const fn remaining_capacity(&self) -> usize {
+        self.capacity() - self.len()
     }
 
-    constify! {
-        if #[cfg(not(miri))]
-        pub fn remaining_capacity(self: &Self) -> usize {
-            self.capacity() - self.len()
+    /// Checks that at least `additional` more elements fit in the `ArrayVec`.
+    /// A [`CapacityError`] is returned if they don't.
+    ///
+    /// The capacity of an `ArrayVec` is fixed, so this never allocates nor changes anything;
+    /// it exists so code written against `Vec::try_reserve` reads the same.
+    #[inline]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), CapacityError> {
+        if additional <= self.remaining_capacity() {
+            Ok(())
+        } else {
+            Err(CapacityError::new(()))
         }
     }
 
+    /// Takes all elements out of the `ArrayVec`,
+    /// and returns them in a new `ArrayVec`, leaving `self` empty.
+    #[inline]
+    pub fn take(&mut self) -> Self {
+        mem::take(self)
+    }
+
+    /// Returns a reference to the whole backing array,
+    /// including the slots past the length.
+    /// Only the first [`ArrayVec::len`] slots are guaranteed to be initialized.
+    #[inline(always)]
+    pub fn as_maybe_uninit_array(&self) -> &[MaybeUninit<T>; N] {
+        &self.array
+    }
+
+    /// Returns a mutable reference to the whole backing array,
+    /// including the slots past the length.
+    ///
+    /// Only the first [`ArrayVec::len`] slots are guaranteed to be initialized.
+    /// Tracking which slots are initialized, and calling [`ArrayVec::set_len`] accordingly,
+    /// is the caller's responsibility.
+    /// Overwriting an initialized slot does not drop the old element.
+    #[inline(always)]
+    pub fn as_maybe_uninit_array_mut(&mut self) -> &mut [MaybeUninit<T>; N] {
+        &mut self.array
+    }
+
     /// Sets the length of the `ArrayVec` to `length`,
     /// without dropping or moving elements.
     ///
@@ -96,7 +230,22 @@ impl<T, const N: usize> ArrayVec<T, { N }> {
         self.len = length;
     }
 
+    /// Increases the length by `additional`, after the slots past the length
+    /// were written to, e.g. through [`ArrayVec::as_maybe_uninit_array_mut`].
+    ///
+    /// # Unsafe
+    /// The `additional` slots directly after the current length must be initialized,
+    /// and the new length must not exceed the capacity.
+    #[inline(always)]
+    pub unsafe fn commit_filled(&mut self, additional: usize) {
+        debug_assert!(additional <= self.remaining_capacity());
+        self.set_len(self.len() + additional);
+    }
+
     /// Push `item` onto the `ArrayVec`.
+    ///
+    /// # Panics
+    /// Panics if the `ArrayVec` is full. Use [`ArrayVec::try_push`] to get `item` back instead.
     #[inline]
     pub fn push(&mut self, item: T) {
         self.try_push(item).unwrap()
@@ -106,7 +255,8 @@ impl<T, const N: usize> ArrayVec<T, { N }> {
     /// Returns `Ok(())` if the ArrayVec had
     /// enough free space for another item.
     /// A [`CapacityError`] is returned when there wasn't enough
-    /// free space for another item.
+    /// free space for another item. It holds `item`, which can be recovered
+    /// with [`CapacityError::into_inner`].
     #[inline]
     pub fn try_push(&mut self, item: T) -> Result<(), CapacityError<T>> {
         if self.len() < self.capacity() {
@@ -117,11 +267,66 @@ impl<T, const N: usize> ArrayVec<T, { N }> {
         }
     }
 
+    /// Tries to push `item` onto the `ArrayVec`,
+    /// and returns the index it was stored at.
+    /// A [`CapacityError`] is returned when there wasn't enough
+    /// free space for another item.
+    #[inline]
+    pub fn try_push_index(&mut self, item: T) -> Result<usize, CapacityError<T>> {
+        let index = self.len();
+        self.try_push(item).map(|()| index)
+    }
+
+    /// Push `item` onto the `ArrayVec`,
+    /// and returns a mutable reference to the pushed item.
+    #[inline]
+    pub fn push_get(&mut self, item: T) -> &mut T {
+        self.try_push_get(item).unwrap()
+    }
+
+    /// Tries to push `item` onto the `ArrayVec`,
+    /// and returns a mutable reference to the pushed item.
+    /// A [`CapacityError`] is returned when there wasn't enough
+    /// free space for another item.
+    #[inline]
+    pub fn try_push_get(&mut self, item: T) -> Result<&mut T, CapacityError<T>> {
+        if self.len() < self.capacity() {
+            unsafe {
+                let len = self.len();
+                self.set_len(len + 1);
+                Ok(self.write_at(len, item))
+            }
+        } else {
+            Err(CapacityError::new(item))
+        }
+    }
+
+    /// Pushes `item` onto the `ArrayVec`, and returns the `ArrayVec`.
+    ///
+    /// This allows building small `ArrayVec`s in one expression:
+    /// `ArrayVec::new().with(1).with(2)`.
+    ///
+    /// # Panics
+    /// Panics if the `ArrayVec` is full.
+    #[inline]
+    pub fn with(mut self, item: T) -> Self {
+        self.push(item);
+        self
+    }
+
+    /// Tries to push `item` onto the `ArrayVec`, and returns the `ArrayVec`.
+    /// A [`CapacityError`] holding `item` is returned when there wasn't enough
+    /// free space for another item, in which case the `ArrayVec` is dropped.
+    #[inline]
+    pub fn try_with(mut self, item: T) -> Result<Self, CapacityError<T>> {
+        self.try_push(item)?;
+        Ok(self)
+    }
+
     #[inline]
     pub unsafe fn push_unchecked(&mut self, item: T) {
         let len = self.len();
-        debug_assert!(len < self.capacity());
-        ptr::write(self.array.get_unchecked_mut(len).as_mut_ptr(), item);
+        self.write_at(len, item);
         self.set_len(len + 1);
     }
 
@@ -132,15 +337,13 @@ impl<T, const N: usize> ArrayVec<T, { N }> {
 
     #[inline]
     pub fn try_insert(&mut self, index: usize, item: T) -> Result<(), CapacityError<T>> {
-        if index > self.len() {
-            panic!()
-        }
+        let len = self.len();
+        assert!(index <= len, "insertion index {} is out of range for ArrayVec of len {}", index, len);
 
         if self.is_full() {
             return Err(CapacityError::new(item));
         }
 
-        let len = self.len();
         unsafe {
             let place_to_insert: *mut MaybeUninit<T> = self.array.get_unchecked_mut(index);
             ptr::copy(place_to_insert, place_to_insert.offset(1), len - index);
@@ -150,6 +353,124 @@ impl<T, const N: usize> ArrayVec<T, { N }> {
         Ok(())
     }
 
+    /// Tries to insert `item` at `index`, shifting all elements after it.
+    /// Unlike [`ArrayVec::try_insert`], an index greater than the length is an error instead of a panic.
+    ///
+    /// [`InsertError::OutOfBounds`] is returned if `index` is greater than the length,
+    /// and [`InsertError::CapacityExceeded`] if the `ArrayVec` is full.
+    #[inline]
+    pub fn try_insert_checked(&mut self, index: usize, item: T) -> Result<(), InsertError<T>> {
+        if index > self.len() {
+            return Err(InsertError::OutOfBounds(item));
+        }
+
+        self.try_insert(index, item).map_err(|e| InsertError::CapacityExceeded(e.into_inner()))
+    }
+
+    /// Tries to insert all elements of `iter` at `index`, keeping their order.
+    /// Either all elements are inserted, or the `ArrayVec` is left unchanged
+    /// and a [`CapacityError`] is returned.
+    ///
+    /// The elements are pushed with [`try_extend`](ArrayVec::try_extend),
+    /// and then rotated into place, so the tail is moved only once.
+    ///
+    /// # Panics
+    /// Panics if `index` is greater than the length.
+    pub fn try_insert_many<I>(&mut self, index: usize, iter: I) -> Result<(), CapacityError>
+    where
+        I: IntoIterator<Item = T>
+    {
+        let len = self.len();
+        assert!(index <= len, "insertion index {} is out of range for ArrayVec of len {}", index, len);
+
+        self.try_extend(iter)?;
+
+        let inserted = self.len() - len;
+        self[index..].rotate_right(inserted);
+        Ok(())
+    }
+
+    /// Inserts `item` at its sorted position, and returns the index it was inserted at.
+    /// A [`CapacityError`] is returned when there wasn't enough
+    /// free space for another item.
+    ///
+    /// The position is found with a binary search,
+    /// so the result is only meaningful if the `ArrayVec` is already sorted.
+    #[inline]
+    pub fn insert_sorted(&mut self, item: T) -> Result<usize, CapacityError<T>>
+    where
+        T: Ord
+    {
+        let index = match self.binary_search(&item) {
+            Ok(index) | Err(index) => index,
+        };
+
+        self.try_insert(index, item).map(|()| index)
+    }
+
+    /// Inserts `item` at its sorted position, unless an equal element is already present.
+    /// Returns the index of `item`, or of the equal element; in that case `item` is dropped.
+    /// A [`CapacityError`] is returned when `item` is absent, and there wasn't enough
+    /// free space for another item.
+    ///
+    /// Unlike [`ArrayVec::insert_sorted`], this never inserts duplicates,
+    /// so a sorted `ArrayVec` can be used as a set.
+    #[inline]
+    pub fn binary_search_insert(&mut self, item: T) -> Result<usize, CapacityError<T>>
+    where
+        T: Ord
+    {
+        match self.binary_search(&item) {
+            Ok(index) => Ok(index),
+            Err(index) => self.try_insert(index, item).map(|()| index),
+        }
+    }
+
+    /// Push `item` onto the front of the `ArrayVec`.
+    #[inline]
+    pub fn push_front(&mut self, item: T) {
+        self.try_push_front(item).unwrap()
+    }
+
+    /// Tries to push `item` onto the front of the `ArrayVec`,
+    /// shifting all elements up by one.
+    /// This is O(n), where n is the length of the `ArrayVec`.
+    /// A [`CapacityError`] is returned when there wasn't enough
+    /// free space for another item.
+    #[inline]
+    pub fn try_push_front(&mut self, item: T) -> Result<(), CapacityError<T>> {
+        self.try_insert(0, item)
+    }
+
+    /// Removes the first element and returns it,
+    /// shifting all other elements down by one.
+    /// This is O(n), where n is the length of the `ArrayVec`.
+    #[inline]
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.is_empty() {
+            None
+        } else {
+            unsafe {
+                let new_len = self.len() - 1;
+                let popped = self.take_at(0);
+                let first_ptr: *mut MaybeUninit<T> = self.array.as_mut_ptr();
+                ptr::copy(first_ptr.add(1), first_ptr, new_len);
+                self.set_len(new_len);
+                Some(popped)
+            }
+        }
+    }
+
+    /// Removes the first element and returns it, or `None` if the `ArrayVec` is empty.
+    /// This is the same as [`ArrayVec::pop_front`], and `Vec::remove(0)`.
+    ///
+    /// The order of the other elements is preserved by shifting them down,
+    /// which is O(n); [`ArrayVec::swap_pop`] with index 0 is O(1), but moves the last element to the front.
+    #[inline(always)]
+    pub fn pop_first(&mut self) -> Option<T> {
+        self.pop_front()
+    }
+
     #[inline]
     pub fn pop(&mut self) -> Option<T> {
         if self.is_empty() {
@@ -157,18 +478,68 @@ impl<T, const N: usize> ArrayVec<T, { N }> {
         } else {
             unsafe {
                 let new_len = self.len() - 1;
-                let popped = self.take(new_len);
+                let popped = self.take_at(new_len);
                 self.set_len(new_len);
                 Some(popped)
             }
         }
     }
 
+    /// Returns a mutable reference to the first element,
+    /// or `None` if the `ArrayVec` is empty.
+    #[inline(always)]
+    pub fn first_mut(&mut self) -> Option<&mut T> {
+        self.as_mut_slice().first_mut()
+    }
+
+    /// Returns a mutable reference to the last element,
+    /// or `None` if the `ArrayVec` is empty.
+    #[inline(always)]
+    pub fn last_mut(&mut self) -> Option<&mut T> {
+        self.as_mut_slice().last_mut()
+    }
+
+    /// Replaces the last element with `item`, and returns the old last element.
+    /// Returns `None` if the `ArrayVec` is empty, in which case `item` is dropped.
+    #[inline]
+    pub fn swap_last(&mut self, item: T) -> Option<T> {
+        self.last_mut().map(|last| mem::replace(last, item))
+    }
+
+    /// Removes the element at `index` and returns it,
+    /// shifting all elements after it down by one.
+    /// This is O(n); [`ArrayVec::swap_remove`] is O(1), but does not preserve the order.
+    ///
+    /// # Panics
+    /// Panics if `index` is not less than the length.
+    #[inline]
+    pub fn remove(&mut self, index: usize) -> T {
+        let len = self.len();
+        assert!(index < len, "removal index {} is out of range for ArrayVec of len {}", index, len);
+
+        unsafe {
+            let removed = self.take_at(index);
+            let place_to_remove: *mut T = (self.array.as_mut_ptr() as *mut T).add(index);
+            ptr::copy(place_to_remove.add(1), place_to_remove, len - index - 1);
+            self.set_len(len - 1);
+            removed
+        }
+    }
+
     #[inline]
     pub fn swap_remove(&mut self, index: usize) -> T {
         self.swap_pop(index).unwrap()
     }
 
+    /// Removes the element at `index`, and returns it.
+    /// The last element takes its place, so this is O(1) but does not preserve the order.
+    /// An [`IndexOutOfBounds`] error is returned if `index` is not less than the length.
+    #[inline]
+    pub fn try_swap_remove(&mut self, index: usize) -> Result<T, IndexOutOfBounds> {
+        let len = self.len();
+        self.swap_pop(index).ok_or(IndexOutOfBounds::new(index, len))
+    }
+
     #[inline]
     pub fn swap_pop(&mut self, index: usize) -> Option<T> {
         let len = self.len();
@@ -180,327 +551,2539 @@ impl<T, const N: usize> ArrayVec<T, { N }> {
         }
     }
 
+    /// Shortens the `ArrayVec` to `new_len` elements, dropping the rest.
+    /// The elements are dropped front to back, like a slice.
+    /// Does nothing if `new_len` is not less than the length.
     #[inline]
     pub fn truncate(&mut self, new_len: usize) {
-        unsafe {
-            if new_len < self.len() {
-                let old_len = self.len();
+        let old_len = self.len();
 
+        if new_len < old_len {
+            unsafe {
                 // panic safety,
                 // dont double drop when destructors panic.
                 self.set_len(new_len);
-                let truncated: *mut [T] = self.get_unchecked_mut(new_len..old_len);
-                // truncated is self[new_len..old_len] *before* we did set the len
-                // to the new len, which we had to do for panic safety.
+
+                // Build the slice of elements to drop from the backing array,
+                // since self[new_len..old_len] is no longer part of the live slice.
+                let first: *mut T = self.array.as_mut_ptr().add(new_len) as *mut T;
+                let truncated: *mut [T] = ptr::slice_from_raw_parts_mut(first, old_len - new_len);
                 ptr::drop_in_place(truncated);
             }
         }
     }
 
-    pub fn try_extend_from_slice(&mut self, slice: &[T]) -> Result<(), CapacityError>
+    /// Overwrites every element with a clone of `value`.
+    /// The length of the `ArrayVec` doesn't change.
+    pub fn fill(&mut self, value: T)
     where
-        T: Copy
+        T: Clone
     {
-        if self.remaining_capacity() < slice.len() {
-            return Err(CapacityError::new(()));
-        } else {
-            let self_len = self.len();
-            let slice_len = slice.len();
-
-            unsafe {
-                let dst = self.array.get_unchecked_mut(0).as_mut_ptr().offset(self_len as isize);
-                ptr::copy_nonoverlapping(slice.as_ptr(), dst, slice_len);
-                self.set_len(self_len + slice_len);
+        if let Some((last, elements)) = self.split_last_mut() {
+            for element in elements {
+                element.clone_from(&value);
             }
-            Ok(())
+
+            *last = value;
         }
     }
 
-
-    #[inline(always)]
-    pub fn clear(&mut self) {
-        self.truncate(0);
+    /// Overwrites every element with a value returned by calling `f`.
+    /// The length of the `ArrayVec` doesn't change.
+    pub fn fill_with<F>(&mut self, mut f: F)
+    where
+        F: FnMut() -> T
+    {
+        for element in self.iter_mut() {
+            *element = f();
+        }
     }
 
-    #[inline(always)]
-    pub fn iter(&self) -> slice::Iter<T> {
-        let slice: &[T] = self;
-        slice.iter()
+    /// Retains only the elements for which `f` returns true,
+    /// preserving their order.
+    #[inline]
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> bool
+    {
+        self.retain_count(f);
     }
 
-    #[inline(always)]
-    pub fn iter_mut(&mut self) -> slice::IterMut<T> {
-        let slice: &mut [T] = self;
-        slice.iter_mut()
-    }
+    /// Retains only the elements for which `f` returns true,
+    /// preserving their order, and returns the number of removed elements.
+    pub fn retain_count<F>(&mut self, mut f: F) -> usize
+    where
+        F: FnMut(&T) -> bool
+    {
+        let len = self.len();
+        let mut gap = FillGapOnDrop { read: 0, write: 0, len, vec: self };
 
-    #[inline]
-    pub fn into_inner(self) -> Result<[T; N], Self> {
-        if !self.is_full() {
-            Err(self)
-        } else {
-            unsafe {
-                let array = self.array.as_ptr() as *const [T; N];
-                let array = ptr::read(array);
-                mem::forget(self);
-                Ok(array)
+        unsafe {
+            gap.vec.set_len(0);
+            let first_ptr: *mut T = gap.vec.array.as_mut_ptr() as *mut T;
+
+            while gap.read < len {
+                let read_ptr = first_ptr.add(gap.read);
+
+                if f(&*read_ptr) {
+                    ptr::copy(read_ptr, first_ptr.add(gap.write), 1);
+                    gap.write += 1;
+                    gap.read += 1;
+                } else {
+                    // Advance first, so a panicking destructor doesn't cause a double drop.
+                    gap.read += 1;
+                    ptr::drop_in_place(read_ptr);
+                }
             }
-        }
-    }
-}
 
-impl <T, const N: usize> Drop for ArrayVec<T, {N}> {
-    fn drop(&mut self) {
-        self.clear()
+            let removed = gap.read - gap.write;
+            gap.vec.set_len(gap.write);
+            mem::forget(gap);
+            removed
+        }
     }
-}
 
-impl <T, const N: usize> Deref for ArrayVec<T, {N}> {
-    type Target = [T];
+    /// Retains only the elements for which `keep` returns true,
+    /// and returns the removed elements in a new `ArrayVec`.
+    /// Both keep their relative order.
+    ///
+    /// If `keep` panics, the elements removed so far are dropped,
+    /// and the `ArrayVec` holds the retained and the unvisited elements.
+    pub fn drain_retain<F>(&mut self, mut keep: F) -> Self
+    where
+        F: FnMut(&mut T) -> bool
+    {
+        let len = self.len();
+        let mut removed = Self::new();
+        let mut gap = FillGapOnDrop { read: 0, write: 0, len, vec: self };
 
-    #[inline]
-    fn deref(&self) -> &Self::Target {
         unsafe {
-            let first_ptr: *const MaybeUninit<T> = self.array.as_ptr();
-            let first_ptr: *const T = first_ptr as *const T;
+            gap.vec.set_len(0);
+            let first_ptr: *mut T = gap.vec.array.as_mut_ptr() as *mut T;
 
-            slice::from_raw_parts(first_ptr, self.len())
+            while gap.read < len {
+                let read_ptr = first_ptr.add(gap.read);
+
+                if keep(&mut *read_ptr) {
+                    ptr::copy(read_ptr, first_ptr.add(gap.write), 1);
+                    gap.write += 1;
+                } else {
+                    removed.push_unchecked(ptr::read(read_ptr));
+                }
+
+                gap.read += 1;
+            }
+
+            gap.vec.set_len(gap.write);
+            mem::forget(gap);
         }
+
+        removed
     }
-}
 
-impl <T, const N: usize> DerefMut for ArrayVec<T, {N}> {
-    #[inline]
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        unsafe {
-            let first_ptr: *mut MaybeUninit<T> = self.array.as_mut_ptr();
+    /// Splits the elements into the ones for which `f` returns true,
+    /// and the ones for which it returns false, preserving their order.
+    /// If `f` panics, all elements are dropped.
+    pub fn partition<F>(self, mut f: F) -> (Self, Self)
+    where
+        F: FnMut(&T) -> bool
+    {
+        let mut matching = Self::new();
+        let mut rest = Self::new();
+
+        for item in self {
+            // Neither can hold more than `N` elements in total.
+            unsafe {
+                if f(&item) {
+                    matching.push_unchecked(item);
+                } else {
+                    rest.push_unchecked(item);
+                }
+            }
+        }
+
+        (matching, rest)
+    }
+
+    /// Sorts the elements, without preserving the order of equal elements.
+    /// This does not allocate, and is available without the `alloc` feature.
+    #[inline]
+    pub fn sort_unstable(&mut self)
+    where
+        T: Ord
+    {
+        self.as_mut_slice().sort_unstable()
+    }
+
+    /// Sorts the elements, preserving the order of equal elements.
+    /// Like [`ArrayVec::sort_by`], this doesn't allocate.
+    #[inline]
+    pub fn sort(&mut self)
+    where
+        T: Ord
+    {
+        self.sort_by(|a, b| a.cmp(b))
+    }
+
+    /// Sorts the elements by the key `f` returns, preserving the order of equal elements.
+    /// Like [`ArrayVec::sort_by`], this doesn't allocate.
+    #[inline]
+    pub fn sort_by_key<K, F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord
+    {
+        self.sort_by(|a, b| f(a).cmp(&f(b)))
+    }
+
+    /// Removes consecutive repeated elements.
+    #[inline]
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq
+    {
+        self.dedup_by(|a, b| a == b)
+    }
+
+    /// Removes consecutive elements that map to the same key.
+    #[inline]
+    pub fn dedup_by_key<K, F>(&mut self, mut key: F)
+    where
+        K: PartialEq,
+        F: FnMut(&mut T) -> K
+    {
+        self.dedup_by(|a, b| key(a) == key(b))
+    }
+
+    /// Removes consecutive elements for which `same_bucket` returns true.
+    /// `same_bucket` is passed the element to be removed first,
+    /// and the element it is compared against (which is kept) second.
+    pub fn dedup_by<F>(&mut self, mut same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool
+    {
+        let len = self.len();
+
+        if len <= 1 {
+            return;
+        }
+
+        let mut gap = FillGapOnDrop { read: 1, write: 1, len, vec: self };
+
+        unsafe {
+            gap.vec.set_len(0);
+            let first_ptr: *mut T = gap.vec.array.as_mut_ptr() as *mut T;
+
+            while gap.read < len {
+                let read_ptr = first_ptr.add(gap.read);
+                let prev_ptr = first_ptr.add(gap.write - 1);
+
+                if same_bucket(&mut *read_ptr, &mut *prev_ptr) {
+                    // Advance first, so a panicking destructor doesn't cause a double drop.
+                    gap.read += 1;
+                    ptr::drop_in_place(read_ptr);
+                } else {
+                    ptr::copy(read_ptr, first_ptr.add(gap.write), 1);
+                    gap.write += 1;
+                    gap.read += 1;
+                }
+            }
+
+            gap.vec.set_len(gap.write);
+            mem::forget(gap);
+        }
+    }
+
+    /// Tries to push all elements of `iter` onto the `ArrayVec`.
+    /// Either all elements are pushed, or the `ArrayVec` is left unchanged
+    /// and a [`CapacityError`] is returned.
+    ///
+    /// If the lower bound of the iterator's `size_hint` already exceeds the remaining capacity,
+    /// the error is returned without consuming the iterator.
+    /// Otherwise elements are pushed one by one; if the iterator turns out to have
+    /// more elements than fit, the pushed elements and the one that didn't fit are dropped.
+    pub fn try_extend<I>(&mut self, iter: I) -> Result<(), CapacityError>
+    where
+        I: IntoIterator<Item = T>
+    {
+        let iter = iter.into_iter();
+
+        if iter.size_hint().0 > self.remaining_capacity() {
+            return Err(CapacityError::new(()));
+        }
+
+        let len = self.len();
+
+        for item in iter {
+            if self.try_push(item).is_err() {
+                self.truncate(len);
+                return Err(CapacityError::new(()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Pushes elements of `iter` until the `ArrayVec` is full,
+    /// and returns the iterator with the elements that didn't fit.
+    ///
+    /// No element is taken from `iter` once the `ArrayVec` is full,
+    /// so the returned iterator still yields the first element that didn't fit.
+    pub fn extend_until_full<I>(&mut self, iter: I) -> I::IntoIter
+    where
+        I: IntoIterator<Item = T>
+    {
+        let mut iter = iter.into_iter();
+
+        while !self.is_full() {
+            match iter.next() {
+                Some(item) => unsafe { self.push_unchecked(item) },
+                None => break,
+            }
+        }
+
+        iter
+    }
+
+    /// Pushes elements of `iter` until the `ArrayVec` is full, like [`Extend`],
+    /// and returns `self` for chaining.
+    ///
+    /// Elements that don't fit are silently left in the iterator;
+    /// use [`ArrayVec::try_extend`] to get an error instead.
+    #[inline]
+    pub fn collect_into<I>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator<Item = T>
+    {
+        self.extend(iter);
+        self
+    }
+
+    /// Pushes elements of `iter` until the `ArrayVec` is full.
+    ///
+    /// If `iter` runs out before that, `Err` is returned with the number of elements that were pushed.
+    /// Those elements are kept in the `ArrayVec`.
+    pub fn fill_exact_from<I>(&mut self, iter: I) -> Result<(), usize>
+    where
+        I: IntoIterator<Item = T>
+    {
+        let len = self.len();
+        let _rest = self.extend_until_full(iter);
+
+        if self.is_full() {
+            Ok(())
+        } else {
+            Err(self.len() - len)
+        }
+    }
+
+    /// Resizes the `ArrayVec` to `new_len`,
+    /// pushing `T::default()` when growing, and truncating when shrinking.
+    ///
+    /// # Panics
+    /// Panics if `new_len` is greater than the capacity.
+    pub fn resize_default(&mut self, new_len: usize)
+    where
+        T: Default
+    {
+        assert!(new_len <= self.capacity(), "new_len {} exceeds the capacity of {}", new_len, self.capacity());
+
+        if new_len < self.len() {
+            self.truncate(new_len);
+        } else {
+            while self.len() < new_len {
+                unsafe { self.push_unchecked(T::default()) }
+            }
+        }
+    }
+
+    /// Makes the contents of the `ArrayVec` a clone of `src`.
+    /// Elements that are already live are assigned with `clone_from`, which can reuse their resources.
+    /// Extra elements are dropped, and missing ones are pushed.
+    ///
+    /// A [`CapacityError`] is returned when `src` is longer than the capacity,
+    /// in which case the `ArrayVec` is left unchanged.
+    ///
+    /// Unlike the slice method with the same name, `src` may have a different length.
+    pub fn clone_from_slice(&mut self, src: &[T]) -> Result<(), CapacityError>
+    where
+        T: Clone
+    {
+        if src.len() > self.capacity() {
+            return Err(CapacityError::new(()));
+        }
+
+        self.truncate(src.len());
+
+        let (overlap, rest) = src.split_at(self.len());
+
+        for (element, src) in self.iter_mut().zip(overlap) {
+            element.clone_from(src);
+        }
+
+        for src in rest {
+            unsafe { self.push_unchecked(src.clone()) }
+        }
+
+        Ok(())
+    }
+
+    /// Copies all elements of `slice` onto the end of the `ArrayVec`.
+    ///
+    /// If `slice` doesn't fit, nothing is copied, and a [`CapacityError`] is returned
+    /// holding `(needed, available)`: the length of `slice`, and the remaining capacity.
+    pub fn try_extend_from_slice(&mut self, slice: &[T]) -> Result<(), CapacityError<(usize, usize)>>
+    where
+        T: Copy
+    {
+        if self.remaining_capacity() < slice.len() {
+            return Err(CapacityError::new((slice.len(), self.remaining_capacity())));
+        } else {
+            let self_len = self.len();
+            let slice_len = slice.len();
+
+            unsafe {
+                let dst = (self.array.as_mut_ptr() as *mut T).add(self_len);
+                ptr::copy_nonoverlapping(slice.as_ptr(), dst, slice_len);
+                self.set_len(self_len + slice_len);
+            }
+            Ok(())
+        }
+    }
+
+
+    /// Copies elements from the front of the `ArrayVec` into `out`, removes them,
+    /// and returns the number of elements copied: the minimum of the length and `out.len()`.
+    /// The remaining elements are shifted down once.
+    pub fn drain_to_slice(&mut self, out: &mut [T]) -> usize
+    where
+        T: Copy
+    {
+        let len = self.len();
+        let n = cmp::min(len, out.len());
+
+        unsafe {
+            let first_ptr: *mut T = self.array.as_mut_ptr() as *mut T;
+            ptr::copy_nonoverlapping(first_ptr, out.as_mut_ptr(), n);
+            ptr::copy(first_ptr.add(n), first_ptr, len - n);
+            self.set_len(len - n);
+        }
+
+        n
+    }
+
+    /// Drops all elements, front to back.
+    /// Use [`ArrayVec::clear_lifo`] to drop them in reverse order.
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Drops all elements, back to front: the last pushed element is dropped first.
+    /// This suits `ArrayVec`s used as a stack of guards that must be released in reverse order.
+    ///
+    /// If dropping an element panics, the elements before it stay in the `ArrayVec`.
+    pub fn clear_lifo(&mut self) {
+        while let Some(item) = self.pop() {
+            drop(item);
+        }
+    }
+
+    /// Returns a slice containing all elements of the `ArrayVec`.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[T] {
+        self
+    }
+
+    /// Returns a mutable slice containing all elements of the `ArrayVec`.
+    #[inline(always)]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+
+    #[inline(always)]
+    pub fn iter(&self) -> slice::Iter<T> {
+        let slice: &[T] = self;
+        slice.iter()
+    }
+
+    #[inline(always)]
+    pub fn iter_mut(&mut self) -> slice::IterMut<T> {
+        let slice: &mut [T] = self;
+        slice.iter_mut()
+    }
+
+    /// Returns an iterator over the elements in non-overlapping arrays of `C` elements,
+    /// starting at the front.
+    ///
+    /// If the length is not a multiple of `C`, the last `len % C` elements are not yielded.
+    /// They can be reached with `v[v.len() - v.len() % C..]`.
+    ///
+    /// # Panics
+    /// Panics if `C` is 0.
+    pub fn array_chunks<const C: usize>(&self) -> impl Iterator<Item = &[T; C]> + '_ {
+        self.chunks_exact(C).map(|chunk| chunk.try_into().unwrap())
+    }
+
+    /// Consumes the `ArrayVec`, and returns an iterator that moves its elements
+    /// into `ArrayVec`s of `C` elements each, starting at the front.
+    /// If the length is not a multiple of `C`, the last chunk holds the remaining `len % C` elements.
+    ///
+    /// Elements that were not yielded in a chunk are dropped when the iterator is dropped.
+    ///
+    /// # Panics
+    /// Panics if `C` is 0.
+    pub fn into_chunks<const C: usize>(self) -> impl Iterator<Item = ArrayVec<T, {C}>> {
+        assert!(C != 0, "chunk size must be non-zero");
+
+        let mut iter = self.into_iter();
+
+        iter::from_fn(move || {
+            let chunk: ArrayVec<T, {C}> = iter.by_ref().take(C).collect();
+
+            if chunk.is_empty() {
+                None
+            } else {
+                Some(chunk)
+            }
+        })
+    }
+
+    /// Returns mutable references to the elements at each of the `indices`.
+    /// Returns `None` if any index is out of bounds,
+    /// or if any index occurs more than once.
+    pub fn get_disjoint_mut<const K: usize>(&mut self, indices: [usize; K]) -> Option<[&mut T; K]> {
+        let len = self.len();
+
+        for (n, &index) in indices.iter().enumerate() {
+            if index >= len || indices[..n].contains(&index) {
+                return None;
+            }
+        }
+
+        unsafe {
+            let first_ptr: *mut T = self.as_mut_ptr();
+            let mut refs: [MaybeUninit<&mut T>; K] = MaybeUninit::uninit().assume_init();
+
+            // The indices are all in bounds and unique,
+            // so none of the references overlap.
+            for (r, &index) in refs.iter_mut().zip(indices.iter()) {
+                *r = MaybeUninit::new(&mut *first_ptr.add(index));
+            }
+
+            Some(ptr::read(refs.as_ptr() as *const [&mut T; K]))
+        }
+    }
+
+    /// Moves the elements of `self` and `other` into a new `ArrayVec`,
+    /// which has room for the elements of both.
+    ///
+    /// The capacity `N + M` is computed from generic consts,
+    /// which needs the `nightly` feature.
+    #[cfg(feature = "nightly")]
+    pub fn concat<const M: usize>(self, other: ArrayVec<T, {M}>) -> ArrayVec<T, {N + M}>
+    where
+        [(); N + M]:
+    {
+        let self_len = self.len();
+        let other_len = other.len();
+        let mut result = ArrayVec::<T, {N + M}>::new();
+
+        unsafe {
+            let dst: *mut T = result.array.as_mut_ptr() as *mut T;
+            ptr::copy_nonoverlapping(self.as_ptr(), dst, self_len);
+            ptr::copy_nonoverlapping(other.as_ptr(), dst.add(self_len), other_len);
+            result.set_len(self_len + other_len);
+        }
+
+        // The elements are owned by `result` now.
+        mem::forget(self);
+        mem::forget(other);
+        result
+    }
+
+    /// Moves the elements into a new `ArrayVec` with a capacity of `M`.
+    /// If there are more than `M` elements, `self` is returned unchanged.
+    pub fn try_into_capacity<const M: usize>(self) -> Result<ArrayVec<T, {M}>, Self> {
+        let len = self.len();
+
+        if len > M {
+            return Err(self);
+        }
+
+        let mut result = ArrayVec::<T, {M}>::new();
+
+        unsafe {
+            ptr::copy_nonoverlapping(self.as_ptr(), result.array.as_mut_ptr() as *mut T, len);
+            result.set_len(len);
+        }
+
+        // The elements are owned by `result` now.
+        mem::forget(self);
+        Ok(result)
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> Result<[T; N], Self> {
+        if !self.is_full() {
+            Err(self)
+        } else {
+            unsafe { Ok(self.into_inner_unchecked()) }
+        }
+    }
+
+    /// Returns the underlying array, or `self` if the `ArrayVec` isn't full.
+    /// This is the same as [`ArrayVec::into_inner`].
+    #[inline(always)]
+    pub fn try_into_array(self) -> Result<[T; N], Self> {
+        self.into_inner()
+    }
+
+    /// Returns the underlying array, without checking whether the `ArrayVec` is full.
+    ///
+    /// # Unsafe
+    /// Calling this when the length isn't equal to the capacity is undefined behaviour,
+    /// because it reads the uninitialized elements past the length.
+    #[inline]
+    pub unsafe fn into_inner_unchecked(self) -> [T; N] {
+        debug_assert!(self.is_full());
+        let array = self.array.as_ptr() as *const [T; N];
+        let array = ptr::read(array);
+        mem::forget(self);
+        array
+    }
+
+    /// Reverses the order of the elements in place.
+    #[inline(always)]
+    pub fn reverse(&mut self) {
+        self.as_mut_slice().reverse()
+    }
+
+    /// Returns the `ArrayVec` with its elements in reverse order.
+    ///
+    /// The elements are swapped in place, so nothing is cloned or dropped.
+    #[inline]
+    pub fn into_reversed(mut self) -> Self {
+        self.reverse();
+        self
+    }
+
+    /// Decomposes the `ArrayVec` into its backing array and its length.
+    /// The first `len` slots of the array are initialized.
+    ///
+    /// The elements are not dropped; use [`ArrayVec::from_parts`] to put the `ArrayVec` back together.
+    #[inline]
+    pub fn into_parts(self) -> ([MaybeUninit<T>; N], usize) {
+        let len = self.len();
+        let array = unsafe { ptr::read(&self.array) };
+        mem::forget(self);
+        (array, len)
+    }
+
+    /// Creates an `ArrayVec` from a backing array and a length.
+    ///
+    /// # Unsafe
+    /// `len` must not be greater than `N`, and the first `len` slots of `array` must be initialized.
+    /// The `ArrayVec` takes ownership of those elements, and drops them.
+    #[inline]
+    pub const unsafe fn from_parts(array: [MaybeUninit<T>; N], len: usize) -> Self {
+        Self { array, len }
+    }
+}
+
+impl <A, B, const N: usize> ArrayVec<(A, B), {N}> {
+    /// Creates an `ArrayVec` of pairs from the elements of `a` and `b`.
+    /// Stops when either iterator runs out, or when the `ArrayVec` is full.
+    #[inline]
+    pub fn zip_from<IA, IB>(a: IA, b: IB) -> Self
+    where
+        IA: IntoIterator<Item = A>,
+        IB: IntoIterator<Item = B>
+    {
+        a.into_iter().zip(b).collect()
+    }
+}
+
+impl <T, const N: usize, const M: usize> ArrayVec<ArrayVec<T, {M}>, {N}> {
+    /// Moves the elements of all inner `ArrayVec`s, in order, into one `ArrayVec`,
+    /// which has room for all of them.
+    ///
+    /// No element is dropped: the elements are moved bit by bit,
+    /// and the emptied inner `ArrayVec`s don't own any elements when they are dropped.
+    ///
+    /// Like [`ArrayVec::concat`], the capacity `N * M` is computed from generic consts,
+    /// which needs the `nightly` feature.
+    #[cfg(feature = "nightly")]
+    pub fn flatten(self) -> ArrayVec<T, {N * M}>
+    where
+        [(); N * M]:
+    {
+        let mut result = ArrayVec::<T, {N * M}>::new();
+
+        for mut inner in self {
+            let len = result.len();
+            let inner_len = inner.len();
+
+            unsafe {
+                let dst: *mut T = (result.array.as_mut_ptr() as *mut T).add(len);
+                ptr::copy_nonoverlapping(inner.as_ptr(), dst, inner_len);
+                // The elements are owned by `result` now.
+                inner.set_len(0);
+                result.set_len(len + inner_len);
+            }
+        }
+
+        result
+    }
+}
+
+/// Used while compacting an `ArrayVec` in place:
+/// elements at `0..write` are kept, elements at `write..read` are removed or moved,
+/// and elements at `read..len` are not visited yet.
+///
+/// On drop, shifts the unvisited elements down to close the gap,
+/// so the `ArrayVec` stays valid if a predicate or destructor panics.
+struct FillGapOnDrop<'a, T, const N: usize> {
+    read: usize,
+    write: usize,
+    len: usize,
+    vec: &'a mut ArrayVec<T, {N}>,
+}
+
+impl <'a, T, const N: usize> Drop for FillGapOnDrop<'a, T, {N}> {
+    fn drop(&mut self) {
+        unsafe {
+            let first_ptr: *mut T = self.vec.array.as_mut_ptr() as *mut T;
+            let unvisited = self.len - self.read;
+            ptr::copy(first_ptr.add(self.read), first_ptr.add(self.write), unvisited);
+            self.vec.set_len(self.write + unvisited);
+        }
+    }
+}
+
+impl <T, const N: usize> Drop for ArrayVec<T, {N}> {
+    fn drop(&mut self) {
+        self.clear()
+    }
+}
+
+impl <T: Clone, const N: usize> Clone for ArrayVec<T, {N}> {
+    /// Clones the elements one by one into a new `ArrayVec`.
+    /// If a clone panics, the elements cloned so far are dropped.
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl <T, const N: usize> Deref for ArrayVec<T, {N}> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        unsafe {
+            let first_ptr: *const MaybeUninit<T> = self.array.as_ptr();
+            let first_ptr: *const T = first_ptr as *const T;
+
+            slice::from_raw_parts(first_ptr, self.len())
+        }
+    }
+}
+
+impl <T, const N: usize> DerefMut for ArrayVec<T, {N}> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe {
+            let first_ptr: *mut MaybeUninit<T> = self.array.as_mut_ptr();
             let first_ptr: *mut T = first_ptr as *mut T;
 
-            slice::from_raw_parts_mut(first_ptr, self.len())
+            slice::from_raw_parts_mut(first_ptr, self.len())
+        }
+    }
+}
+
+impl <T, const N: usize> AsRef<[T]> for ArrayVec<T, {N}> {
+    #[inline(always)]
+    fn as_ref(&self) -> &[T] {
+        self
+    }
+}
+
+impl <T, const N: usize> AsMut<[T]> for ArrayVec<T, {N}> {
+    #[inline(always)]
+    fn as_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl <T, const N: usize> Borrow<[T]> for ArrayVec<T, {N}> {
+    #[inline(always)]
+    fn borrow(&self) -> &[T] {
+        self
+    }
+}
+
+impl <T, const N: usize> BorrowMut<[T]> for ArrayVec<T, {N}> {
+    #[inline(always)]
+    fn borrow_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl <T: PartialEq, const N: usize> PartialEq for ArrayVec<T, {N}> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl <T: Eq, const N: usize> Eq for ArrayVec<T, {N}> {}
+
+/// Compares the elements lexicographically, like slices.
+impl <T: PartialOrd, const N: usize> PartialOrd for ArrayVec<T, {N}> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        (**self).partial_cmp(&**other)
+    }
+}
+
+/// Compares the elements lexicographically, like slices.
+impl <T: Ord, const N: usize> Ord for ArrayVec<T, {N}> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        (**self).cmp(&**other)
+    }
+}
+
+/// Hashes the same way as the slice of elements,
+/// which is required for the `Borrow<[T]>` impl.
+impl <T: Hash, const N: usize> Hash for ArrayVec<T, {N}> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Hash::hash(&**self, state)
+    }
+}
+
+/// Pushes elements until the `ArrayVec` is full; the rest of the iterator is not consumed.
+/// Use [`ArrayVec::try_extend`] to get an error instead.
+impl <T, const N: usize> Extend<T> for ArrayVec<T, {N}> {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>
+    {
+        #[cfg(feature = "nightly")]
+        spec_extend::SpecExtend::spec_extend(self, iter.into_iter());
+
+        #[cfg(not(feature = "nightly"))]
+        self.extend_from_iter(iter.into_iter());
+    }
+}
+
+impl <T, const N: usize> ArrayVec<T, {N}> {
+    /// Pushes elements of `iter` until the `ArrayVec` is full.
+    /// This is the general implementation of `Extend`.
+    fn extend_from_iter<I>(&mut self, mut iter: I)
+    where
+        I: Iterator<Item = T>
+    {
+        let take = self.remaining_capacity();
+
+        let array = &mut self.array;
+        let len = &mut self.len;
+        
+        unsafe {
+            for dst in array.get_unchecked_mut(*len..*len + take) {
+                match iter.next() {
+                    Some(item) => {
+                        ptr::write(dst.as_mut_ptr(), item);
+                        *len += 1;
+                    }
+                    None => break,
+                }
+            }
+        }
+    }
+}
+
+/// Collects at most `N` elements; the rest of the iterator is not consumed.
+/// Use [`IteratorExt::try_collect_arrayvec`] to get an error instead.
+impl <T, const N: usize> FromIterator<T> for ArrayVec<T, {N}> {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>
+    {
+        let mut array = ArrayVec::<T, {N}>::default();
+        array.extend(iter);
+        array
+    }
+}
+
+impl <'a, T, const N: usize> IntoIterator for &'a ArrayVec<T, {N}> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl <'a, T, const N: usize> IntoIterator for &'a mut ArrayVec<T, {N}> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl <T, const N: usize> IntoIterator for ArrayVec<T, {N}> {
+    type Item = T;
+    type IntoIter = IntoIter<T, {N}>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter::<T, {N}> {
+            array: self,
+            index: 0,
+        }
+    }
+}
+
+/// Formats the elements like a slice.
+impl <T: fmt::Debug, const N: usize> fmt::Debug for ArrayVec<T, {N}> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+/// Formats each byte as two lowercase hex digits, without separators.
+/// The `#` flag adds a single `0x` prefix.
+impl <const N: usize> fmt::LowerHex for ArrayVec<u8, {N}> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+
+        for byte in self.iter() {
+            write!(f, "{:02x}", byte)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Formats each byte as two uppercase hex digits, without separators.
+/// The `#` flag adds a single `0x` prefix.
+impl <const N: usize> fmt::UpperHex for ArrayVec<u8, {N}> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+
+        for byte in self.iter() {
+            write!(f, "{:02X}", byte)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl <const N: usize> ArrayVec<u8, {N}> {
+    /// Copies as many bytes of `src` as fit in the remaining capacity,
+    /// and returns the number of bytes copied.
+    #[inline]
+    pub fn put_slice(&mut self, src: &[u8]) -> usize {
+        let len = self.len();
+        let n = cmp::min(src.len(), self.remaining_capacity());
+
+        unsafe {
+            let dst = (self.array.as_mut_ptr() as *mut u8).add(len);
+            ptr::copy_nonoverlapping(src.as_ptr(), dst, n);
+            self.set_len(len + n);
+        }
+
+        n
+    }
+}
+
+/// Writes as many bytes as fit in the remaining capacity.
+/// `write_all` fails with [`std::io::ErrorKind::WriteZero`] once the `ArrayVec` is full.
+#[cfg(feature = "std")]
+impl <const N: usize> std::io::Write for ArrayVec<u8, {N}> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        Ok(self.put_slice(buf))
+    }
+
+    #[inline(always)]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Moves the elements of the `ArrayVec` into a newly allocated `Vec`.
+#[cfg(feature = "alloc")]
+impl <T, const N: usize> From<ArrayVec<T, {N}>> for alloc::vec::Vec<T> {
+    fn from(array: ArrayVec<T, {N}>) -> Self {
+        let len = array.len();
+        let mut vec = alloc::vec::Vec::with_capacity(len);
+
+        unsafe {
+            ptr::copy_nonoverlapping(array.as_ptr(), vec.as_mut_ptr(), len);
+            vec.set_len(len);
+        }
+
+        // The elements are owned by `vec` now.
+        mem::forget(array);
+        vec
+    }
+}
+
+/// Moves the elements out of `slice` into an `ArrayVec`,
+/// leaving `T::default()` in their place.
+/// If `slice` holds more than `N` elements, a [`CapacityError`] is returned,
+/// and `slice` is left unchanged.
+impl <'a, T: Default, const N: usize> core::convert::TryFrom<&'a mut [T]> for ArrayVec<T, {N}> {
+    type Error = CapacityError;
+
+    fn try_from(slice: &'a mut [T]) -> Result<Self, Self::Error> {
+        if slice.len() > N {
+            return Err(CapacityError::new(()));
+        }
+
+        let mut array = Self::new();
+
+        for element in slice {
+            unsafe { array.push_unchecked(mem::take(element)) }
+        }
+
+        Ok(array)
+    }
+}
+
+/// Moves the elements of `vec` into an `ArrayVec`.
+/// If `vec` holds more than `N` elements, it is returned unchanged.
+#[cfg(feature = "alloc")]
+impl <T, const N: usize> core::convert::TryFrom<alloc::vec::Vec<T>> for ArrayVec<T, {N}> {
+    type Error = alloc::vec::Vec<T>;
+
+    fn try_from(mut vec: alloc::vec::Vec<T>) -> Result<Self, Self::Error> {
+        let len = vec.len();
+
+        if len > N {
+            return Err(vec);
+        }
+
+        let mut array = Self::default();
+
+        unsafe {
+            // The elements are owned by `array` now,
+            // so `vec` must not drop them.
+            vec.set_len(0);
+            ptr::copy_nonoverlapping(vec.as_ptr(), array.array.as_mut_ptr() as *mut T, len);
+            array.set_len(len);
+        }
+
+        Ok(array)
+    }
+}
+
+/// An iterator that moves the elements out of an [`ArrayVec`].
+///
+/// Every element is dropped exactly once: either by the caller after it was yielded,
+/// or by the `IntoIter` when it is dropped. This also holds for the default
+/// iterator methods such as `find` and `rfind`, even if their closure panics.
+pub struct IntoIter<T, const N: usize> {
+    /// The elements at `index..array.len()` are not yielded yet.
+    /// Yielding from the back shrinks the length of `array`.
+    array: ArrayVec<T, {N}>,
+    /// Index of the next element to yield from the front.
+    /// Always `index <= array.len()`; both ends meet when they are equal.
+    index: usize,
+}
+
+impl <T, const N: usize> IntoIter<T, {N}> {
+    /// Returns the elements that are not yielded yet as a slice.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        unsafe {
+            let first_ptr: *const T = self.array.array.as_ptr() as *const T;
+            slice::from_raw_parts(first_ptr.add(self.index), self.len())
+        }
+    }
+
+    /// Returns the elements that are not yielded yet as a mutable slice.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe {
+            let len = self.len();
+            let first_ptr: *mut T = self.array.array.as_mut_ptr() as *mut T;
+            slice::from_raw_parts_mut(first_ptr.add(self.index), len)
+        }
+    }
+
+    /// Returns a reference to the element `index` positions after
+    /// the next element to be yielded from the front,
+    /// or `None` if there are not that many elements left.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.as_slice().get(index)
+    }
+
+    /// Returns a mutable reference to the element `index` positions after
+    /// the next element to be yielded from the front,
+    /// or `None` if there are not that many elements left.
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.as_mut_slice().get_mut(index)
+    }
+}
+
+impl <T, const N: usize> Drop for IntoIter<T, {N}> {
+    fn drop(&mut self) {
+        let len = self.array.len();
+        let index = self.index;
+
+        unsafe {
+            // Drop the elements between index..len. Those before `index` were
+            // yielded from the front, those after `len` were yielded from the back.
+            self.array.set_len(0);
+            let first: *mut T = self.array.array.as_mut_ptr().add(index) as *mut T;
+            let elements: *mut [T] = ptr::slice_from_raw_parts_mut(first, len - index);
+            ptr::drop_in_place(elements);
+        }
+    }
+}
+
+impl <T, const N: usize> Iterator for IntoIter<T, {N}> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index == self.array.len() {
+            None
+        } else {
+            unsafe {
+                let elem = self.array.take_at(self.index);
+                self.index += 1;
+                Some(elem)
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.array.len() - self.index;
+        (len, Some(len))
+    }
+
+    /// Drops the next `n` elements, and returns the element after them.
+    /// If there are `n` or fewer elements left, they are all dropped and `None` is returned.
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let skip = cmp::min(n, self.len());
+
+        unsafe {
+            let first: *mut T = (self.array.array.as_mut_ptr() as *mut T).add(self.index);
+            // Advance first, so a panicking destructor doesn't cause a double drop.
+            self.index += skip;
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(first, skip));
+        }
+
+        self.next()
+    }
+
+    /// Returns the number of remaining elements, and drops them.
+    #[inline]
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    /// Returns the last element, and drops all other remaining elements.
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl <T, const N: usize> DoubleEndedIterator for IntoIter<T, {N}> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index == self.array.len() {
+            None
+        } else {
+            unsafe {
+                let new_len = self.array.len() - 1;
+                let elem = self.array.take_at(new_len);
+                self.array.set_len(new_len);
+                Some(elem)
+            }
+        }
+    }
+
+    /// Drops the next `n` elements from the back, and returns the element before them.
+    /// If there are `n` or fewer elements left, they are all dropped and `None` is returned.
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let skip = cmp::min(n, self.len());
+        let new_len = self.array.len() - skip;
+
+        unsafe {
+            let first: *mut T = (self.array.array.as_mut_ptr() as *mut T).add(new_len);
+            // Shrink first, so a panicking destructor doesn't cause a double drop.
+            self.array.set_len(new_len);
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(first, skip));
+        }
+
+        self.next_back()
+    }
+}
+
+impl <T, const N: usize> ExactSizeIterator for IntoIter<T, {N}> {}
+impl <T, const N: usize> FusedIterator for IntoIter<T, {N}> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{Counted, DropCounter};
+    use std::{boxed::Box, string::{String, ToString}};
+
+    fn length_erasure<T, const N: usize>(_: &ArrayVec<T, {N}>) {
+
+    }
+
+    #[test]
+    fn test_to_ensure_it_doesnt_ice() {
+
+        let mut v = ArrayVec::<u8, { 399 }>::default();
+
+        length_erasure(&v);
+
+        assert!(v.is_empty());
+        assert!(!v.is_full());
+        assert_eq!(v.capacity(), 399);
+
+        v.push(20);
+        v.push(30);
+
+        assert_eq!(v.len(), 2);
+
+        assert_eq!(v.pop(), Some(30));
+        assert_eq!(v.len(), 1);
+
+        v.extend(0..10);
+        
+        assert_eq!(&*v, &[20, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        assert_eq!(v.len(), 11);
+
+        v.clear();
+
+        assert!(v.is_empty());
+
+        assert!(v.into_inner().is_err());
+
+    }
+
+    #[test]
+    fn into_iter() {
+        let mut v = ArrayVec::<usize, {10}>::default();
+
+        for n in 0..v.capacity() {
+            v.push(n);
+        }
+
+        let mut iter = v.into_iter();
+
+        assert_eq!(iter.next_back(), Some(9));
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next_back(), Some(8));
+        assert_eq!(iter.next_back(), Some(7));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), Some(3));
+        assert_eq!(iter.next_back(), Some(6));
+        assert_eq!(iter.next_back(), Some(5));
+        assert_eq!(iter.next(), Some(4));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        let mut v: ArrayVec<i32, {10}> = (0..10).collect();
+
+        let mut iter = v.into_iter();
+    }
+
+    #[test]
+    fn empty_array_vec() {
+        let mut v: ArrayVec<String, {0}> = std::iter::repeat(String::from("WORLD")).take(100).collect();
+        mem::forget(v);
+    }
+
+    #[test]
+    fn test_collect() {
+        let mut v: ArrayVec<String, {10}> = std::iter::repeat(String::from("Helloooooo there")).take(100).collect();
+        assert_eq!(v.len(), 10);
+        let array = v.into_inner();
+    }
+
+    #[test]
+    //#[should_panic]
+    fn panic_while_truncate() {
+        struct DropPanic(Box<usize>);
+
+        let mut v: ArrayVec<DropPanic, {20}> = (0..10).map(Box::new).map(DropPanic).collect();
+
+        v.truncate(5);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn io_write_short() {
+        use std::io::{ErrorKind, Write};
+
+        let mut v = ArrayVec::<u8, {3}>::default();
+
+        assert_eq!(v.write(&[1, 2, 3, 4, 5]).unwrap(), 3);
+        assert_eq!(&*v, &[1, 2, 3]);
+        assert_eq!(v.write(&[6]).unwrap(), 0);
+
+        v.clear();
+        let err = v.write_all(&[1, 2, 3, 4, 5]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::WriteZero);
+        assert_eq!(&*v, &[1, 2, 3]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn into_vec() {
+        use std::vec::Vec;
+
+        let mut v = ArrayVec::<String, {10}>::default();
+        v.push(String::from("a"));
+        v.push(String::from("b"));
+        v.push(String::from("c"));
+
+        let vec: Vec<String> = v.into();
+        assert_eq!(vec, ["a", "b", "c"]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn try_from_vec() {
+        use core::convert::TryFrom;
+        use std::vec;
+
+        let vec = vec![String::from("a"), String::from("b")];
+        let v = ArrayVec::<String, {2}>::try_from(vec).unwrap();
+        assert_eq!(&*v, &["a", "b"]);
+
+        let vec = vec![String::from("a"), String::from("b"), String::from("c")];
+        let vec = ArrayVec::<String, {2}>::try_from(vec).err().unwrap();
+        assert_eq!(vec, ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn const_new() {
+        const V: ArrayVec<u8, {16}> = ArrayVec::new();
+
+        let mut v = V;
+        assert!(v.is_empty());
+        assert_eq!(v.capacity(), 16);
+
+        v.push(1);
+        assert_eq!(&*v, &[1]);
+    }
+
+    #[test]
+    fn fill_to_capacity() {
+        let mut v = ArrayVec::<usize, {4}>::new();
+
+        for n in 0..v.capacity() {
+            v.push(n);
+        }
+
+        assert!(v.is_full());
+        assert_eq!(v.len(), 4);
+        assert!(v.try_push(4).is_err());
+    }
+
+    #[test]
+    fn is_empty_shared_ref() {
+        fn check<T, const N: usize>(v: &ArrayVec<T, {N}>) -> bool {
+            v.is_empty()
+        }
+
+        let mut v = ArrayVec::<u8, {2}>::new();
+        assert!(check(&v));
+
+        v.push(1);
+        assert!(!check(&v));
+    }
+
+    #[test]
+    fn truncate_drops_tail() {
+        let drops = DropCounter::new();
+        let mut v: ArrayVec<Counted, {8}> = (0..6).map(|n| drops.counted(n)).collect();
+
+        v.truncate(2);
+        assert_eq!(v.len(), 2);
+        assert_eq!(&*drops.order(), &[2, 3, 4, 5]);
+
+        v.truncate(4);
+        assert_eq!(drops.total(), 4);
+
+        drop(v);
+        assert!(drops.all_dropped_once(6));
+    }
+
+    #[test]
+    fn capacity_const() {
+        let array = [0u8; ArrayVec::<String, {7}>::CAPACITY];
+
+        assert_eq!(array.len(), 7);
+        assert_eq!(ArrayVec::<String, {7}>::CAPACITY, ArrayVec::<String, {7}>::new().capacity());
+    }
+
+    #[test]
+    fn get_disjoint_mut() {
+        let mut v: ArrayVec<i32, {4}> = (1..4).collect();
+
+        if let Some([a, b]) = v.get_disjoint_mut([0, 2]) {
+            core::mem::swap(a, b);
+        } else {
+            panic!("indices 0 and 2 are disjoint");
+        }
+        assert_eq!(&*v, &[3, 2, 1]);
+
+        assert!(v.get_disjoint_mut([1, 1]).is_none());
+        assert!(v.get_disjoint_mut([0, 3]).is_none());
+    }
+
+    #[test]
+    fn push_pop_front() {
+        let mut v = ArrayVec::<String, {3}>::new();
+
+        v.push_front(String::from("c"));
+        v.push_front(String::from("b"));
+        v.push_front(String::from("a"));
+        assert!(v.try_push_front(String::from("z")).is_err());
+
+        assert_eq!(v.pop_front().as_deref(), Some("a"));
+        v.push(String::from("d"));
+        assert_eq!(&*v, &["b", "c", "d"]);
+
+        assert_eq!(v.pop_front().as_deref(), Some("b"));
+        assert_eq!(v.pop_front().as_deref(), Some("c"));
+        assert_eq!(v.pop_front().as_deref(), Some("d"));
+        assert_eq!(v.pop_front(), None);
+    }
+
+    #[test]
+    fn insert_sorted() {
+        let mut v: ArrayVec<i32, {5}> = [1, 2, 4, 5].iter().copied().collect();
+
+        assert_eq!(v.insert_sorted(3).ok(), Some(2));
+        assert_eq!(&*v, &[1, 2, 3, 4, 5]);
+
+        assert!(v.insert_sorted(0).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "nightly")]
+    fn concat() {
+        let a: ArrayVec<String, {2}> = (1..3).map(|n| n.to_string()).collect();
+        let b: ArrayVec<String, {3}> = (3..6).map(|n| n.to_string()).collect();
+
+        let v: ArrayVec<String, {5}> = a.concat(b);
+        assert_eq!(&*v, &["1", "2", "3", "4", "5"]);
+        assert!(v.is_full());
+    }
+
+    #[test]
+    fn try_into_capacity() {
+        let v: ArrayVec<String, {4}> = (0..4).map(|n| n.to_string()).collect();
+
+        let mut v = v.try_into_capacity::<{8}>().ok().unwrap();
+        assert_eq!(v.capacity(), 8);
+        assert_eq!(&*v, &["0", "1", "2", "3"]);
+
+        v.push(String::from("4"));
+
+        let v = v.try_into_capacity::<{2}>().err().unwrap();
+        assert_eq!(&*v, &["0", "1", "2", "3", "4"]);
+    }
+
+    #[test]
+    fn zero_sized_types() {
+        let mut v = ArrayVec::<(), {8}>::new();
+
+        for _ in 0..v.capacity() {
+            v.push(());
+        }
+        assert!(v.is_full());
+        assert!(v.try_push(()).is_err());
+        assert_eq!(v.iter().count(), 8);
+
+        assert_eq!(v.pop(), Some(()));
+        v.extend((0..10).map(|_| ()));
+        assert_eq!(v.len(), 8);
+
+        let mut iter = v.into_iter();
+        assert_eq!(iter.next(), Some(()));
+        assert_eq!(iter.next_back(), Some(()));
+        assert_eq!(iter.len(), 6);
+        drop(iter);
+
+        let mut v = ArrayVec::<(), {usize::MAX}>::new();
+        v.extend((0..100).map(|_| ()));
+        v.try_extend_from_slice(&[(); 10]).unwrap();
+        assert_eq!(v.len(), 110);
+        assert_eq!(v.remaining_capacity(), usize::MAX - 110);
+        assert_eq!(v.into_iter().count(), 110);
+    }
+
+    #[test]
+    fn zero_capacity() {
+        let mut v = ArrayVec::<u8, {0}>::new();
+
+        assert!(v.is_full());
+        assert!(v.try_push(0).is_err());
+        assert!(v.try_extend_from_slice(&[]).is_ok());
+        assert!(v.try_extend_from_slice(&[0]).is_err());
+        assert_eq!(v.into_iter().next(), None);
+    }
+
+    #[test]
+    fn iterate_by_reference() {
+        let mut v: ArrayVec<usize, {4}> = (1..4).collect();
+
+        for n in &mut v {
+            *n *= 10;
+        }
+
+        let mut sum = 0;
+        for n in &v {
+            sum += *n;
+        }
+
+        assert_eq!(sum, 60);
+    }
+
+    #[test]
+    fn as_slice() {
+        let mut v: ArrayVec<usize, {8}> = (1..5).collect();
+
+        assert_eq!(v.iter().sum::<usize>(), 10);
+
+        v.as_mut_slice()[0] = 5;
+        assert_eq!(v.as_slice(), &[5, 2, 3, 4]);
+    }
+
+    #[test]
+    fn clone() {
+        use std::{vec, vec::Vec};
+
+        let mut v = ArrayVec::<Vec<usize>, {4}>::new();
+        v.push(vec![1, 2]);
+        v.push(vec![3]);
+
+        let mut cloned = v.clone();
+        cloned[0].push(10);
+        cloned.pop();
+
+        assert_eq!(&*v, &[vec![1, 2], vec![3]]);
+        assert_eq!(&*cloned, &[vec![1, 2, 10]]);
+    }
+
+    #[test]
+    fn take() {
+        let mut v: ArrayVec<String, {4}> = (0..3).map(|n| n.to_string()).collect();
+
+        let taken = v.take();
+        assert!(v.is_empty());
+        assert_eq!(&*taken, &["0", "1", "2"]);
+
+        v.push(String::from("3"));
+        assert_eq!(&*v, &["3"]);
+    }
+
+    #[test]
+    fn try_from_results() {
+        let parsed = ArrayVec::<i32, {4}>::try_from_results(["1", "2", "3"].iter().map(|s| s.parse::<i32>()));
+        assert_eq!(parsed.ok().as_deref(), Some(&[1, 2, 3][..]));
+
+        let parsed = ArrayVec::<i32, {4}>::try_from_results(["1", "2", "x", "4"].iter().map(|s| s.parse::<i32>()));
+        assert!(parsed.is_err());
+
+        let parsed = ArrayVec::<i32, {2}>::try_from_results(["1", "2", "x"].iter().map(|s| s.parse::<i32>()));
+        assert_eq!(parsed.ok().as_deref(), Some(&[1, 2][..]));
+    }
+
+    #[test]
+    fn dedup() {
+        let mut v: ArrayVec<String, {8}> = [1, 1, 2, 3, 3, 3].iter().map(|n| n.to_string()).collect();
+        v.dedup();
+        assert_eq!(&*v, &["1", "2", "3"]);
+
+        let mut v: ArrayVec<i32, {8}> = [10, 11, 20, 21, 22, 30].iter().copied().collect();
+        v.dedup_by_key(|n| *n / 10);
+        assert_eq!(&*v, &[10, 20, 30]);
+
+        let mut v: ArrayVec<i32, {8}> = ArrayVec::new();
+        v.dedup();
+        assert!(v.is_empty());
+    }
+
+    #[test]
+    fn dedup_panic_safety() {
+        use std::panic::{self, AssertUnwindSafe};
+
+        let mut v: ArrayVec<String, {8}> = [1, 1, 2, 3, 3].iter().map(|n| n.to_string()).collect();
+        let mut calls = 0;
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            v.dedup_by(|a, b| {
+                calls += 1;
+                if calls == 3 {
+                    panic!("same_bucket panicked");
+                }
+                a == b
+            })
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(&*v, &["1", "2", "3", "3"]);
+    }
+
+    #[test]
+    fn array_vec_macro() {
+        let v: ArrayVec<i32, {4}> = array_vec![1, 2, 3];
+        assert_eq!(&*v, &[1, 2, 3]);
+
+        let v: ArrayVec<u8, {4}> = array_vec![0u8; 4];
+        assert_eq!(&*v, &[0, 0, 0, 0]);
+
+        let v: ArrayVec<String, {2}> = array_vec![];
+        assert!(v.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "more elements than the capacity of 2")]
+    fn array_vec_macro_overflow() {
+        let _: ArrayVec<i32, {2}> = array_vec![1, 2, 3];
+    }
+
+    #[test]
+    #[should_panic(expected = "more elements than the capacity of 2")]
+    fn array_vec_macro_repeat_overflow() {
+        let _: ArrayVec<u8, {2}> = array_vec![0; 3];
+    }
+
+    #[test]
+    fn push_get() {
+        let mut v = ArrayVec::<String, {2}>::new();
+
+        v.push_get(String::from("a")).push('!');
+        *v.try_push_get(String::from("b")).unwrap() += "?";
+        assert!(v.try_push_get(String::from("c")).is_err());
+
+        assert_eq!(&*v, &["a!", "b?"]);
+    }
+
+    #[test]
+    fn fill() {
+        let mut v: ArrayVec<i32, {5}> = array_vec![1, 2, 3];
+
+        v.fill(0);
+        assert_eq!(&*v, &[0, 0, 0]);
+
+        let mut n = 0;
+        v.fill_with(|| {
+            n += 1;
+            n
+        });
+        assert_eq!(&*v, &[1, 2, 3]);
+
+        let mut v: ArrayVec<String, {5}> = ArrayVec::new();
+        v.fill(String::from("unused"));
+        assert!(v.is_empty());
+    }
+
+    #[test]
+    fn into_array() {
+        let v: ArrayVec<String, {3}> = (0..3).map(|n| n.to_string()).collect();
+        let array = unsafe { v.into_inner_unchecked() };
+        assert_eq!(array, ["0", "1", "2"]);
+
+        let v: ArrayVec<String, {3}> = (0..2).map(|n| n.to_string()).collect();
+        let mut v = v.try_into_array().err().unwrap();
+
+        v.push(String::from("2"));
+        assert_eq!(v.try_into_array().ok().unwrap(), ["0", "1", "2"]);
+    }
+
+    #[test]
+    fn borrow_as_slice_key() {
+        use std::collections::HashMap;
+
+        fn total<S: AsRef<[u8]>>(s: S) -> u32 {
+            s.as_ref().iter().map(|&n| u32::from(n)).sum()
+        }
+
+        let mut map = HashMap::new();
+        map.insert(array_vec![1u8, 2], 12);
+        map.insert(ArrayVec::<u8, {4}>::new(), 0);
+
+        assert_eq!(map.get(&[1u8, 2][..]), Some(&12));
+        assert_eq!(map.get(&[][..]), Some(&0));
+        assert_eq!(map.get(&[2u8, 1][..]), None);
+
+        let mut v: ArrayVec<u8, {4}> = array_vec![1, 2, 3];
+        v.as_mut()[0] = 4;
+        assert_eq!(total(&v), 9);
+    }
+
+    #[test]
+    fn try_extend() {
+        struct Hinted<'a> {
+            next_calls: &'a mut usize,
+            lower: usize,
+        }
+
+        impl Iterator for Hinted<'_> {
+            type Item = usize;
+
+            fn next(&mut self) -> Option<usize> {
+                *self.next_calls += 1;
+                Some(*self.next_calls)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (self.lower, None)
+            }
+        }
+
+        let mut v: ArrayVec<usize, {4}> = array_vec![0];
+        let mut next_calls = 0;
+
+        assert!(v.try_extend(Hinted { next_calls: &mut next_calls, lower: 4 }).is_err());
+        assert_eq!(next_calls, 0);
+        assert_eq!(&*v, &[0]);
+
+        assert!(v.try_extend(Hinted { next_calls: &mut next_calls, lower: 0 }).is_err());
+        assert_eq!(next_calls, 4);
+        assert_eq!(&*v, &[0]);
+
+        assert!(v.try_extend(1..4).is_ok());
+        assert_eq!(&*v, &[0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn retain_count() {
+        let mut v: ArrayVec<String, {10}> = (0..10).map(|n| n.to_string()).collect();
+
+        assert_eq!(v.retain_count(|s| s.parse::<i32>().unwrap() % 2 == 0), 5);
+        assert_eq!(&*v, &["0", "2", "4", "6", "8"]);
+
+        v.retain(|s| s != "4");
+        assert_eq!(&*v, &["0", "2", "6", "8"]);
+    }
+
+    #[test]
+    fn swap_last() {
+        let mut v = ArrayVec::<i32, {4}>::new();
+        assert_eq!(v.swap_last(1), None);
+        assert!(v.is_empty());
+
+        v.push(1);
+        v.push(2);
+        assert_eq!(v.swap_last(3), Some(2));
+        assert_eq!(&*v, &[1, 3]);
+
+        *v.first_mut().unwrap() += 10;
+        *v.last_mut().unwrap() += 10;
+        assert_eq!(&*v, &[11, 13]);
+    }
+
+    #[test]
+    fn into_iter_peek() {
+        let v: ArrayVec<i32, {8}> = (0..6).collect();
+        let mut iter = v.into_iter();
+
+        assert_eq!(iter.get(0), Some(&0));
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.next_back(), Some(5));
+
+        assert_eq!(iter.as_slice(), &[1, 2, 3, 4]);
+        assert_eq!(iter.get(0), Some(&1));
+        assert_eq!(iter.get(3), Some(&4));
+        assert_eq!(iter.get(4), None);
+
+        *iter.get_mut(1).unwrap() = 20;
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), Some(20));
+    }
+
+    #[test]
+    fn into_iter_count_last() {
+        let drops = DropCounter::new();
+
+        let v: ArrayVec<Counted, {8}> = (0..6).map(|n| drops.counted(n)).collect();
+        let mut iter = v.into_iter();
+        iter.next();
+        assert_eq!(drops.total(), 1);
+        assert_eq!(iter.count(), 5);
+        assert!(drops.all_dropped_once(6));
+
+        drops.reset();
+        let v: ArrayVec<Counted, {8}> = (0..6).map(|n| drops.counted(n)).collect();
+        let last = v.into_iter().last().unwrap();
+        assert_eq!(last.id, 5);
+        assert_eq!(drops.total(), 5);
+        drop(last);
+        assert!(drops.all_dropped_once(6));
+
+        let v = ArrayVec::<Counted, {8}>::new();
+        assert!(v.into_iter().last().is_none());
+    }
+
+    #[test]
+    fn into_iter_nth() {
+        let v: ArrayVec<String, {10}> = (0..10).map(|n| n.to_string()).collect();
+        let mut iter = v.into_iter();
+
+        assert_eq!(iter.nth(2).as_deref(), Some("2"));
+        assert_eq!(iter.nth_back(2).as_deref(), Some("7"));
+        assert_eq!(iter.as_slice(), &["3", "4", "5", "6"]);
+
+        assert_eq!(iter.nth(1).as_deref(), Some("4"));
+        assert_eq!(iter.nth_back(0).as_deref(), Some("6"));
+
+        assert_eq!(iter.nth(2), None);
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next_back(), None);
+
+        let v: ArrayVec<String, {10}> = (0..10).map(|n| n.to_string()).collect();
+        let mut iter = v.into_iter();
+        assert_eq!(iter.nth_back(10), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn partition() {
+        let v: ArrayVec<String, {6}> = (0..6).map(|n| n.to_string()).collect();
+
+        let (even, odd) = v.partition(|s| s.parse::<i32>().unwrap() % 2 == 0);
+        assert_eq!(&*even, &["0", "2", "4"]);
+        assert_eq!(&*odd, &["1", "3", "5"]);
+    }
+
+    #[test]
+    fn zip_from() {
+        let v = ArrayVec::<_, {4}>::zip_from(0..3, ['a', 'b', 'c', 'd'].iter().copied());
+        assert_eq!(&*v, &[(0, 'a'), (1, 'b'), (2, 'c')]);
+
+        let v = ArrayVec::<_, {2}>::zip_from(0.., "xyz".chars());
+        assert_eq!(&*v, &[(0, 'x'), (1, 'y')]);
+    }
+
+    #[test]
+    fn resize_default() {
+        let mut v: ArrayVec<i32, {4}> = array_vec![1];
+
+        v.resize_default(3);
+        assert_eq!(&*v, &[1, 0, 0]);
+
+        v.resize_default(1);
+        assert_eq!(&*v, &[1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "new_len 5 exceeds the capacity of 4")]
+    fn resize_default_over_capacity() {
+        let mut v = ArrayVec::<i32, {4}>::new();
+        v.resize_default(5);
+    }
+
+    #[test]
+    fn clone_from_slice() {
+        let five: ArrayVec<String, {5}> = (0..5).map(|n| n.to_string()).collect();
+        let mut v: ArrayVec<String, {5}> = (10..13).map(|n| n.to_string()).collect();
+
+        v.clone_from_slice(&five).unwrap();
+        assert_eq!(&*v, &*five);
+
+        v.clone_from_slice(&five[2..]).unwrap();
+        assert_eq!(&*v, &["2", "3", "4"]);
+
+        let six: ArrayVec<String, {6}> = (0..6).map(|n| n.to_string()).collect();
+        assert!(v.clone_from_slice(&six).is_err());
+        assert_eq!(&*v, &["2", "3", "4"]);
+    }
+
+    #[test]
+    fn maybe_uninit_array() {
+        let mut v = ArrayVec::<String, {4}>::new();
+
+        for (n, slot) in v.as_maybe_uninit_array_mut().iter_mut().enumerate() {
+            *slot = MaybeUninit::new(n.to_string());
+        }
+
+        unsafe { v.set_len(4) }
+
+        assert_eq!(&*v, &["0", "1", "2", "3"]);
+        assert_eq!(v.as_maybe_uninit_array().len(), 4);
+    }
+
+    #[test]
+    fn hex() {
+        use std::format;
+
+        let v: ArrayVec<u8, {4}> = array_vec![0x0a, 0xff];
+
+        assert_eq!(format!("{:x}", v), "0aff");
+        assert_eq!(format!("{:X}", v), "0AFF");
+        assert_eq!(format!("{:#x}", v), "0x0aff");
+        assert_eq!(format!("{:x}", ArrayVec::<u8, {4}>::new()), "");
+    }
+
+    #[test]
+    fn with() {
+        let v = ArrayVec::<i32, {3}>::new().with(1).with(2).with(3);
+        assert_eq!(&*v, &[1, 2, 3]);
+
+        let v = ArrayVec::<i32, {3}>::new().try_with(1).and_then(|v| v.try_with(2));
+        assert_eq!(v.map(|v| v.len()).ok(), Some(2));
+
+        let full = ArrayVec::<i32, {3}>::new().with(1).with(2).with(3);
+        match full.try_with(4) {
+            Err(e) => assert_eq!(e.into_inner(), 4),
+            Ok(_) => panic!("pushed past the capacity"),
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn with_overflow() {
+        ArrayVec::<i32, {1}>::new().with(1).with(2);
+    }
+
+    #[test]
+    fn into_iter_double_ended_drops() {
+        // Every sequence of front and back calls, one past exhausting the iterator,
+        // yields the right elements, and dropping the iterator after it drops the rest exactly once.
+        const K: usize = 4;
+
+        for steps in 0..=K + 1 {
+            for calls in 0..1usize << steps {
+                let drops = DropCounter::new();
+
+                let v: ArrayVec<Counted, {K}> = (0..K).map(|n| drops.counted(n)).collect();
+                let mut iter = v.into_iter();
+                let (mut front, mut back) = (0, K);
+
+                for step in 0..steps {
+                    if (calls >> step) & 1 == 0 {
+                        let expected = if front < back { front += 1; Some(front - 1) } else { None };
+                        assert_eq!(iter.next().map(|c| c.id), expected);
+                    } else {
+                        let expected = if front < back { back -= 1; Some(back) } else { None };
+                        assert_eq!(iter.next_back().map(|c| c.id), expected);
+                    }
+                    assert_eq!(iter.len(), back - front);
+                }
+
+                assert_eq!(drops.total(), K - (back - front));
+                drop(iter);
+                assert!(drops.all_dropped_once(K), "steps: {}, calls: {:b}", steps, calls);
+            }
+        }
+
+        // Front and back meet on the last element.
+        let drops = DropCounter::new();
+        let v: ArrayVec<Counted, {3}> = (0..3).map(|n| drops.counted(n)).collect();
+        let mut iter = v.into_iter();
+
+        assert_eq!(iter.next().map(|c| c.id), Some(0));
+        assert_eq!(iter.next_back().map(|c| c.id), Some(2));
+        assert_eq!(iter.next_back().map(|c| c.id), Some(1));
+        assert!(iter.next().is_none());
+        assert!(iter.next_back().is_none());
+        assert_eq!(iter.len(), 0);
+
+        drop(iter);
+        assert!(drops.all_dropped_once(3));
+    }
+
+    #[test]
+    fn layout() {
+        use core::mem::{align_of, size_of};
+
+        let word = size_of::<usize>();
+
+        // `ArrayVec` is `repr(Rust)`, so only bound its size by the array plus the length and padding.
+        assert!(size_of::<ArrayVec<u8, {0}>>() <= word);
+        assert!(size_of::<ArrayVec<u8, {4}>>() <= 4 + 2 * word);
+        assert!(size_of::<ArrayVec<u8, {255}>>() <= 255 + 2 * word);
+        assert!(align_of::<ArrayVec<u8, {4}>>() <= align_of::<usize>());
+
+        assert_eq!(ArrayVec::<u8, {0}>::INDEX_BYTES, 1);
+        assert_eq!(ArrayVec::<u8, {255}>::INDEX_BYTES, 1);
+        assert_eq!(ArrayVec::<u8, {256}>::INDEX_BYTES, 2);
+        assert_eq!(ArrayVec::<(), {65536}>::INDEX_BYTES, 4);
+    }
+
+    #[test]
+    fn try_insert_many() {
+        let mut v: ArrayVec<i32, {6}> = [1, 2, 3].iter().copied().collect();
+
+        v.try_insert_many(1, [8, 9].iter().copied()).unwrap();
+        assert_eq!(&*v, &[1, 8, 9, 2, 3]);
+
+        v.try_insert_many(5, None).unwrap();
+        assert_eq!(&*v, &[1, 8, 9, 2, 3]);
+
+        assert!(v.try_insert_many(0, 0..2).is_err());
+        assert_eq!(&*v, &[1, 8, 9, 2, 3]);
+
+        v.try_insert_many(0, Some(0)).unwrap();
+        assert_eq!(&*v, &[0, 1, 8, 9, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn try_insert_many_out_of_range() {
+        let mut v: ArrayVec<i32, {6}> = [1, 2, 3].iter().copied().collect();
+        let _ = v.try_insert_many(4, Some(0));
+    }
+
+    #[test]
+    fn capacity_helpers() {
+        let mut v = ArrayVec::<u8, {3}>::new();
+        assert_eq!(v.capacity(), 3);
+
+        for n in 0..3 {
+            assert!(!v.is_full());
+            assert_eq!(v.remaining_capacity(), 3 - n as usize);
+            v.push(n);
+        }
+
+        assert!(v.is_full());
+        assert_eq!(v.remaining_capacity(), 0);
+        assert_eq!(v.capacity(), 3);
+    }
+
+    #[test]
+    fn into_inner() {
+        let v: ArrayVec<usize, {3}> = (0..3).collect();
+        assert_eq!(v.into_inner().ok(), Some([0, 1, 2]));
+
+        let v: ArrayVec<usize, {3}> = (0..2).collect();
+        let v = v.into_inner().err().unwrap();
+        assert_eq!(&*v, &[0, 1]);
+    }
+
+    #[test]
+    fn write_at_take_at() {
+        let mut v = ArrayVec::<String, {2}>::new();
+
+        unsafe {
+            v.write_at(1, String::from("b")).push('!');
+            v.write_at(0, String::from("a"));
+            v.set_len(2);
         }
-    }
-}
 
-impl <T, const N: usize> Extend<T> for ArrayVec<T, {N}> {
-    fn extend<I>(&mut self, iter: I)
-    where
-        I: IntoIterator<Item = T>
-    {
-        let take = self.remaining_capacity();
-        
-        let mut iter = iter.into_iter();
+        assert_eq!(&*v, &["a", "b!"]);
 
-        let array = &mut self.array;
-        let len = &mut self.len;
-        
         unsafe {
-            for dst in array.get_unchecked_mut(*len..*len + take) {
-                match iter.next() {
-                    Some(item) => {
-                        ptr::write(dst.as_mut_ptr(), item);
-                        *len += 1;
-                    }
-                    None => break,
-                }
-            }
+            assert_eq!(v.take_at(0), "a");
+            v.write_at(0, String::from("c"));
         }
+
+        assert_eq!(&*v, &["c", "b!"]);
     }
-}
 
-impl <T, const N: usize> FromIterator<T> for ArrayVec<T, {N}> {
-    fn from_iter<I>(iter: I) -> Self
-    where
-        I: IntoIterator<Item = T>
-    {
-        let mut array = ArrayVec::<T, {N}>::default();
-        array.extend(iter);
-        array
+    #[test]
+    fn try_swap_remove() {
+        let mut v: ArrayVec<i32, {4}> = [1, 2, 3, 4].iter().copied().collect();
+
+        assert_eq!(v.try_swap_remove(0), Ok(1));
+        assert_eq!(&*v, &[4, 2, 3]);
+
+        let err = v.try_swap_remove(3).unwrap_err();
+        assert_eq!((err.index(), err.vec_len()), (3, 3));
+        assert_eq!(err.to_string(), "index 3 out of range for ArrayVec of len 3");
+        assert_eq!(&*v, &[4, 2, 3]);
     }
-}
 
-impl <'a, T, const N: usize> IntoIterator for &'a ArrayVec<T, {N}> {
-    type Item = &'a T;
-    type IntoIter = slice::Iter<'a, T>;
+    #[test]
+    fn array_chunks() {
+        let v: ArrayVec<i32, {8}> = (1..=7).collect();
 
-    #[inline(always)]
-    fn into_iter(self) -> Self::IntoIter {
-        self.iter()
+        let mut chunks = v.array_chunks::<3>();
+        assert_eq!(chunks.next(), Some(&[1, 2, 3]));
+        assert_eq!(chunks.next(), Some(&[4, 5, 6]));
+        assert_eq!(chunks.next(), None);
+
+        assert_eq!(v.array_chunks::<8>().count(), 0);
     }
-}
 
-impl <'a, T, const N: usize> IntoIterator for &'a mut ArrayVec<T, {N}> {
-    type Item = &'a mut T;
-    type IntoIter = slice::IterMut<'a, T>;
+    #[test]
+    fn try_reserve() {
+        let mut v: ArrayVec<u8, {4}> = (0..1).collect();
+
+        assert!(v.try_reserve(3).is_ok());
+        assert!(v.try_reserve(0).is_ok());
+        assert!(v.try_reserve(4).is_err());
+        assert!(v.try_reserve(usize::MAX).is_err());
+        assert_eq!(&*v, &[0]);
+    }
 
-    #[inline(always)]
-    fn into_iter(self) -> Self::IntoIter {
-        self.iter_mut()
+    #[test]
+    fn capacity_in_const_context() {
+        const V: &ArrayVec<u8, {4}> = &ArrayVec::new();
+        const FULL: bool = V.is_full();
+        const CAPACITY: usize = V.capacity();
+        const REMAINING: usize = V.remaining_capacity();
+
+        assert_eq!((FULL, CAPACITY, REMAINING), (false, 4, 4));
     }
-}
 
-impl <T, const N: usize> IntoIterator for ArrayVec<T, {N}> {
-    type Item = T;
-    type IntoIter = IntoIter<T, {N}>;
+    #[test]
+    fn extend_until_full() {
+        let mut v = ArrayVec::<i32, {3}>::new();
 
-    #[inline(always)]
-    fn into_iter(self) -> Self::IntoIter {
-        IntoIter::<T, {N}> {
-            array: self,
-            index: 0,
-        }
+        let rest: ArrayVec<i32, {5}> = v.extend_until_full(0..5).collect();
+        assert_eq!(&*v, &[0, 1, 2]);
+        assert_eq!(&*rest, &[3, 4]);
+
+        let mut v = ArrayVec::<i32, {3}>::new();
+        let mut rest = v.extend_until_full(0..2);
+        assert_eq!(rest.next(), None);
+        assert_eq!(&*v, &[0, 1]);
     }
-}
 
-pub struct IntoIter<T, const N: usize> {
-    array: ArrayVec<T, {N}>,
-    index: usize,
-}
+    #[test]
+    fn try_push_index() {
+        let mut v = ArrayVec::<char, {3}>::new();
 
-impl <T, const N: usize> Drop for IntoIter<T, {N}> {
-    fn drop(&mut self) {
-        let len = self.array.len();
-        let index = self.index;
+        assert_eq!(v.try_push_index('a').ok(), Some(0));
+        assert_eq!(v.try_push_index('b').ok(), Some(1));
+        assert_eq!(v.try_push_index('c').ok(), Some(2));
+        assert_eq!(v.try_push_index('d').unwrap_err().into_inner(), 'd');
+    }
 
-        unsafe {
-            // Drop the elements between index..len.
-            
-            self.array.set_len(0);
-            let elements: *mut [T] = self.array.get_unchecked_mut(index..len);
-            ptr::drop_in_place(elements);
-        }
+    #[test]
+    fn sort() {
+        let mut v: ArrayVec<i32, {3}> = [3, 1, 2].iter().copied().collect();
+        v.sort_unstable();
+        assert_eq!(&*v, &[1, 2, 3]);
     }
-}
 
-impl <T, const N: usize> Iterator for IntoIter<T, {N}> {
-    type Item = T;
+    #[test]
+    fn sort_stable() {
+        let mut v: ArrayVec<i32, {3}> = [3, 1, 2].iter().copied().collect();
+        v.sort();
+        assert_eq!(&*v, &[1, 2, 3]);
+
+        let mut v: ArrayVec<(i32, char), {4}> = [(1, 'a'), (0, 'b'), (1, 'c'), (0, 'd')].iter().copied().collect();
+        v.sort_by_key(|&(key, _)| key);
+        assert_eq!(&*v, &[(0, 'b'), (0, 'd'), (1, 'a'), (1, 'c')]);
+    }
 
-    #[inline]
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.index == self.array.len() {
-            None
-        } else {
-            unsafe {
-                let elem = self.array.take(self.index);
-                self.index += 1;
-                Some(elem)
-            }
+    #[test]
+    fn try_insert_checked() {
+        let mut v: ArrayVec<i32, {3}> = [1, 3].iter().copied().collect();
+
+        v.try_insert_checked(1, 2).unwrap();
+        assert_eq!(&*v, &[1, 2, 3]);
+
+        match v.try_insert_checked(4, 5) {
+            Err(InsertError::OutOfBounds(item)) => assert_eq!(item, 5),
+            other => panic!("expected OutOfBounds, got {:?}", other),
+        }
+
+        match v.try_insert_checked(0, 0) {
+            Err(InsertError::CapacityExceeded(item)) => assert_eq!(item, 0),
+            other => panic!("expected CapacityExceeded, got {:?}", other),
         }
+
+        assert_eq!(&*v, &[1, 2, 3]);
     }
 
-    #[inline]
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        let len = self.array.len() - self.index;
-        (len, Some(len))
+    #[test]
+    fn into_parts() {
+        let v: ArrayVec<String, {4}> = (0..3).map(|n| n.to_string()).collect();
+
+        let (array, len) = v.into_parts();
+        assert_eq!(len, 3);
+        assert_eq!(unsafe { &*array[2].as_ptr() }, "2");
+
+        let v = unsafe { ArrayVec::from_parts(array, len) };
+        assert_eq!(&*v, &["0", "1", "2"]);
     }
-}
 
-impl <T, const N: usize> DoubleEndedIterator for IntoIter<T, {N}> {
-    #[inline]
-    fn next_back(&mut self) -> Option<Self::Item> {
-        if self.index == self.array.len() {
-            None
-        } else {
-            unsafe {
-                let new_len = self.array.len() - 1;
-                let elem = self.array.take(new_len);
-                self.array.set_len(new_len);
-                Some(elem)
-            }
+    #[test]
+    fn from_parts_manually_filled() {
+        let mut array: [MaybeUninit<String>; 4] = unsafe { MaybeUninit::uninit().assume_init() };
+
+        for (n, slot) in array.iter_mut().take(2).enumerate() {
+            *slot = MaybeUninit::new(n.to_string());
         }
+
+        let mut v = unsafe { ArrayVec::<String, {4}>::from_parts(array, 2) };
+        assert_eq!(&*v, &["0", "1"]);
+
+        v.push(String::from("2"));
+        assert_eq!(v.pop().as_deref(), Some("2"));
+        assert_eq!(v.len(), 2);
     }
-}
 
-impl <T, const N: usize> ExactSizeIterator for IntoIter<T, {N}> {}
-impl <T, const N: usize> FusedIterator for IntoIter<T, {N}> {}
+    #[test]
+    fn binary_search_insert() {
+        let mut v = ArrayVec::<i32, {3}>::new();
+
+        assert_eq!(v.binary_search_insert(5).ok(), Some(0));
+        assert_eq!(v.binary_search_insert(1).ok(), Some(0));
+        assert_eq!(v.binary_search_insert(5).ok(), Some(1));
+        assert_eq!(&*v, &[1, 5]);
+
+        assert_eq!(v.binary_search_insert(3).ok(), Some(1));
+        assert_eq!(v.binary_search_insert(3).ok(), Some(1));
+        assert_eq!(v.binary_search_insert(4).unwrap_err().into_inner(), 4);
+        assert_eq!(&*v, &[1, 3, 5]);
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn into_reversed() {
+        let v: ArrayVec<i32, {4}> = (1..=3).collect();
+        assert_eq!(&*v.into_reversed(), &[3, 2, 1]);
 
-    fn length_erasure<T, const N: usize>(_: &ArrayVec<T, {N}>) {
+        let v: ArrayVec<String, {4}> = (1..=4).map(|n| n.to_string()).collect();
+        assert_eq!(&*v.into_reversed(), &["4", "3", "2", "1"]);
+    }
 
+    #[test]
+    fn pop_first() {
+        let mut v: ArrayVec<i32, {3}> = (1..=3).collect();
+
+        assert_eq!(v.pop_first(), Some(1));
+        assert_eq!(&*v, &[2, 3]);
+
+        v.clear();
+        assert_eq!(v.pop_first(), None);
     }
 
     #[test]
-    fn test_to_ensure_it_doesnt_ice() {
+    fn into_iter_find_drops() {
+        use std::panic::{self, AssertUnwindSafe};
+
+        let drops = DropCounter::new();
+        let new = || (0..6).map(|n| drops.counted(n)).collect::<ArrayVec<Counted, {6}>>();
+
+        let mut iter = new().into_iter();
+        let found = iter.find(|c| c.id == 2).unwrap();
+        assert_eq!(&*drops.order(), &[0, 1]);
+        let found_back = iter.rfind(|c| c.id == 4).unwrap();
+        assert_eq!(drops.count(5), 1);
+        drop((iter, found, found_back));
+        assert!(drops.all_dropped_once(6));
+
+        drops.reset();
+
+        let mut iter = new().into_iter();
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            iter.rfind(|c| if c.id == 3 { panic!("boom") } else { false })
+        }));
+        assert!(result.is_err());
+        assert_eq!(iter.len(), 3);
+        drop(iter);
+        assert!(drops.all_dropped_once(6));
+    }
 
-        let mut v = ArrayVec::<u8, { 399 }>::default();
+    #[test]
+    fn fill_exact_from() {
+        let mut v: ArrayVec<i32, {4}> = Some(0).into_iter().collect();
 
-        length_erasure(&v);
+        assert_eq!(v.fill_exact_from(1..3), Err(2));
+        assert_eq!(&*v, &[0, 1, 2]);
 
-        assert!(v.is_empty());
-        assert!(!v.is_full());
-        assert_eq!(v.capacity(), 399);
+        assert_eq!(v.fill_exact_from(3..10), Ok(()));
+        assert_eq!(&*v, &[0, 1, 2, 3]);
+    }
 
-        v.push(20);
-        v.push(30);
+    #[test]
+    fn try_extend_from_slice_error() {
+        let mut v: ArrayVec<u8, {5}> = (0..2).collect();
 
+        let err = v.try_extend_from_slice(&[0; 5]).unwrap_err();
+        assert_eq!(err.into_inner(), (5, 3));
         assert_eq!(v.len(), 2);
 
-        assert_eq!(v.pop(), Some(30));
-        assert_eq!(v.len(), 1);
+        v.try_extend_from_slice(&[2, 3, 4]).unwrap();
+        assert_eq!(&*v, &[0, 1, 2, 3, 4]);
+    }
 
-        v.extend(0..10);
-        
-        assert_eq!(&*v, &[20, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
-        assert_eq!(v.len(), 11);
+    #[test]
+    fn put_slice() {
+        let mut v: ArrayVec<u8, {4}> = (0..3).collect();
+
+        assert_eq!(v.put_slice(&[3, 4, 5]), 1);
+        assert_eq!(&*v, &[0, 1, 2, 3]);
+
+        assert_eq!(v.put_slice(&[6]), 0);
+        assert_eq!(v.put_slice(&[]), 0);
+        assert_eq!(&*v, &[0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn drain_retain() {
+        let mut v: ArrayVec<i32, {6}> = (0..6).collect();
+
+        let odds = v.drain_retain(|n| *n % 2 == 0);
+        assert_eq!(&*v, &[0, 2, 4]);
+        assert_eq!(&*odds, &[1, 3, 5]);
+
+        let mut v: ArrayVec<String, {6}> = (0..6).map(|n| n.to_string()).collect();
+        let removed = v.drain_retain(|s| {
+            s.push('!');
+            s != "1!"
+        });
+        assert_eq!(&*v, &["0!", "2!", "3!", "4!", "5!"]);
+        assert_eq!(&*removed, &["1!"]);
+    }
+
+    #[test]
+    fn drain_retain_panic_safety() {
+        use std::panic::{self, AssertUnwindSafe};
+
+        let mut v: ArrayVec<String, {6}> = (0..6).map(|n| n.to_string()).collect();
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            v.drain_retain(|s| match s.as_str() {
+                "3" => panic!("boom"),
+                s => s != "1",
+            })
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(&*v, &["0", "2", "3", "4", "5"]);
+    }
 
+    #[test]
+    fn clear_drop_order() {
+        let drops = DropCounter::new();
+
+        let mut v: ArrayVec<Counted, {4}> = (0..4).map(|n| drops.counted(n)).collect();
         v.clear();
+        assert_eq!(&*drops.order(), &[0, 1, 2, 3]);
 
+        drops.reset();
+
+        v.extend((0..4).map(|n| drops.counted(n)));
+        v.clear_lifo();
         assert!(v.is_empty());
+        assert_eq!(&*drops.order(), &[3, 2, 1, 0]);
+    }
 
-        assert!(v.into_inner().is_err());
+    #[test]
+    fn commit_filled() {
+        let mut v: ArrayVec<String, {4}> = Some(String::from("0")).into_iter().collect();
+
+        let len = v.len();
+        for (n, slot) in v.as_maybe_uninit_array_mut()[len..3].iter_mut().enumerate() {
+            *slot = MaybeUninit::new((len + n).to_string());
+        }
 
+        unsafe { v.commit_filled(2) }
+        assert_eq!(&*v, &["0", "1", "2"]);
     }
 
     #[test]
-    fn into_iter() {
-        let mut v = ArrayVec::<usize, {10}>::default();
+    fn try_from_mut_slice() {
+        use core::convert::TryFrom;
 
-        for n in 0..v.capacity() {
-            v.push(n);
-        }
+        let mut strings = [String::from("a"), String::from("b"), String::from("c")];
 
-        let mut iter = v.into_iter();
+        assert!(ArrayVec::<String, {2}>::try_from(&mut strings[..]).is_err());
+        assert_eq!(strings, ["a", "b", "c"]);
 
-        assert_eq!(iter.next_back(), Some(9));
-        assert_eq!(iter.next(), Some(0));
-        assert_eq!(iter.next(), Some(1));
-        assert_eq!(iter.next_back(), Some(8));
-        assert_eq!(iter.next_back(), Some(7));
-        assert_eq!(iter.next(), Some(2));
-        assert_eq!(iter.next(), Some(3));
-        assert_eq!(iter.next_back(), Some(6));
-        assert_eq!(iter.next_back(), Some(5));
-        assert_eq!(iter.next(), Some(4));
-        assert_eq!(iter.next(), None);
-        assert_eq!(iter.next_back(), None);
+        let v = ArrayVec::<String, {4}>::try_from(&mut strings[..]).unwrap();
+        assert_eq!(&*v, &["a", "b", "c"]);
+        assert_eq!(strings, ["", "", ""]);
+    }
 
-        let mut v: ArrayVec<i32, {10}> = (0..10).collect();
+    #[test]
+    fn into_chunks() {
+        let v: ArrayVec<String, {8}> = (0..7).map(|n| n.to_string()).collect();
 
-        let mut iter = v.into_iter();
+        let lens: ArrayVec<usize, {3}> = v.clone().into_chunks::<3>().map(|chunk| chunk.len()).collect();
+        assert_eq!(&*lens, &[3, 3, 1]);
+
+        let mut chunks = v.into_chunks::<3>();
+        assert_eq!(&*chunks.next().unwrap(), &["0", "1", "2"]);
+        assert_eq!(&*chunks.next().unwrap(), &["3", "4", "5"]);
+        assert_eq!(&*chunks.next().unwrap(), &["6"]);
+        assert!(chunks.next().is_none());
     }
 
     #[test]
-    fn empty_array_vec() {
-        let mut v: ArrayVec<String, {0}> = std::iter::repeat(String::from("WORLD")).take(100).collect();
-        mem::forget(v);
+    fn into_chunks_drops_remainder() {
+        let drops = DropCounter::new();
+        let v: ArrayVec<Counted, {7}> = (0..7).map(|n| drops.counted(n)).collect();
+
+        let mut chunks = v.into_chunks::<3>();
+        let first = chunks.next().unwrap();
+        assert_eq!(drops.total(), 0);
+
+        drop(chunks);
+        assert_eq!(&*drops.order(), &[3, 4, 5, 6]);
+        drop(first);
+        assert!(drops.all_dropped_once(7));
     }
 
     #[test]
-    fn test_collect() {
-        let mut v: ArrayVec<String, {10}> = std::iter::repeat(String::from("Helloooooo there")).take(100).collect();
-        assert_eq!(v.len(), 10);
-        let array = v.into_inner();
+    fn compare() {
+        let a: ArrayVec<i32, {4}> = [1, 2, 3].iter().copied().collect();
+        let b: ArrayVec<i32, {4}> = [1, 2, 4].iter().copied().collect();
+        let c: ArrayVec<i32, {4}> = [1, 2].iter().copied().collect();
+
+        assert!(a == a.clone());
+        assert!(a != b);
+        assert!(a < b);
+        assert!(c < a);
+        assert_eq!(a.cmp(&b), core::cmp::Ordering::Less);
+        assert_eq!(b.cmp(&c), core::cmp::Ordering::Greater);
+        assert_eq!(a.partial_cmp(&a.clone()), Some(core::cmp::Ordering::Equal));
     }
 
     #[test]
-    //#[should_panic]
-    fn panic_while_truncate() {
-        struct DropPanic(Box<usize>);
+    fn debug() {
+        use std::format;
 
-        let mut v: ArrayVec<DropPanic, {20}> = (0..10).map(Box::new).map(DropPanic).collect();
+        let v: ArrayVec<&str, {4}> = ["a", "b"].iter().copied().collect();
+        assert_eq!(format!("{:?}", v), r#"["a", "b"]"#);
+        assert_eq!(format!("{:?}", ArrayVec::<i32, {4}>::default()), "[]");
+    }
 
-        v.truncate(5);
-    } 
+    #[test]
+    fn insert_remove() {
+        let mut v: ArrayVec<usize, {5}> = (0..4).collect();
+
+        v.insert(2, 9);
+        assert_eq!(&*v, &[0, 1, 9, 2, 3]);
+        assert!(v.try_insert(0, 7).is_err());
+
+        assert_eq!(v.remove(2), 9);
+        assert_eq!(&*v, &[0, 1, 2, 3]);
+
+        assert_eq!(v.remove(3), 3);
+        assert_eq!(v.swap_remove(0), 0);
+        assert_eq!(&*v, &[2, 1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn remove_out_of_range() {
+        let mut v: ArrayVec<usize, {5}> = (0..4).collect();
+        v.remove(4);
+    }
+
+    #[test]
+    fn collect_and_extend_truncate() {
+        let mut v: ArrayVec<usize, {3}> = (0..5).collect();
+        assert_eq!(&*v, &[0, 1, 2]);
+
+        v.pop();
+        let mut rest = 5..10;
+        v.extend(rest.by_ref());
+        assert_eq!(&*v, &[0, 1, 5]);
+        assert_eq!(rest.next(), Some(6));
+
+        assert!((0..5).try_collect_arrayvec::<3>().is_err());
+        assert!(v.try_extend(Some(0)).is_err());
+    }
+
+    #[test]
+    fn array_string_macro() {
+        let s = array_string!(16; "{}-{}", 12, 345).unwrap();
+        assert_eq!(s.as_str(), "12-345");
+        assert_eq!(s.capacity(), 16);
+
+        assert!(array_string!(4; "{}-{}", 12, 345).is_err());
+        assert_eq!(array_string!(0; "").unwrap().as_str(), "");
+    }
+
+    #[test]
+    fn reverse() {
+        let mut v: ArrayVec<i32, {4}> = (1..=3).collect();
+        v.reverse();
+        assert_eq!(&*v, &[3, 2, 1]);
+    }
+
+    #[test]
+    fn collect_into() {
+        let mut v = ArrayVec::<i32, {5}>::new();
+
+        v.collect_into(0..2).collect_into(Some(9)).collect_into(10..20);
+        assert_eq!(&*v, &[0, 1, 9, 10, 11]);
+
+        assert_eq!(v.collect_into(None).len(), 5);
+    }
+
+    #[test]
+    fn drain_to_slice() {
+        let mut v: ArrayVec<u8, {4}> = (1..=4).collect();
+        let mut out = [0; 2];
+
+        assert_eq!(v.drain_to_slice(&mut out), 2);
+        assert_eq!(out, [1, 2]);
+        assert_eq!(&*v, &[3, 4]);
+
+        let mut out = [0; 3];
+        assert_eq!(v.drain_to_slice(&mut out), 2);
+        assert_eq!(out, [3, 4, 0]);
+        assert!(v.is_empty());
+
+        assert_eq!(v.drain_to_slice(&mut out), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "nightly")]
+    fn flatten() {
+        let mut v = ArrayVec::<ArrayVec<i32, {2}>, {3}>::new();
+        v.push((1..=2).collect());
+        v.push(Some(3).into_iter().collect());
+        v.push(ArrayVec::new());
+
+        let flat = v.flatten();
+        assert_eq!(&*flat, &[1, 2, 3]);
+        assert_eq!(flat.capacity(), 6);
+
+        let v: ArrayVec<ArrayVec<String, {2}>, {2}> = (0..2)
+            .map(|n| (0..2).map(|m| (2 * n + m).to_string()).collect())
+            .collect();
+        assert_eq!(&*v.flatten(), &["0", "1", "2", "3"]);
+    }
+
+    #[test]
+    #[cfg(feature = "nightly")]
+    fn flatten_panicking_drop() {
+        use std::panic::{self, AssertUnwindSafe};
+
+        let drops = DropCounter::new();
+        let mut v = ArrayVec::<ArrayVec<Counted, {2}>, {2}>::new();
+        let mut inner = ArrayVec::new();
+        inner.push(drops.counted(0));
+        inner.push(drops.bomb(1));
+        v.push(inner);
+        v.push(Some(drops.counted(2)).into_iter().collect());
+
+        // Moving the elements doesn't drop any of them.
+        let flat = v.flatten();
+        assert_eq!(drops.total(), 0);
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| drop(flat)));
+        assert!(result.is_err());
+        assert!(drops.all_dropped_once(3));
+    }
 }
@@ -10,4 +10,57 @@ macro_rules! constify {
         #[cfg(not($cfg))]
         $visibility fn $fnname($($argname: $argtype),*) -> $ret $implementation
     }
-}
\ No newline at end of file
+}
+
+/// Creates an [`ArrayVec`](crate::ArrayVec) containing the arguments.
+///
+/// - `array_vec![a, b, c]` pushes each element in order.
+/// - `array_vec![elem; n]` pushes `n` clones of `elem`.
+///
+/// The capacity is never inferred from the number of elements:
+/// it comes from the type the macro is used as,
+/// e.g. `let v: ArrayVec<u8, 4> = array_vec![1, 2];`.
+///
+/// # Panics
+/// Panics if there are more elements than the capacity.
+#[macro_export]
+macro_rules! array_vec {
+    ($elem:expr; $n:expr) => {{
+        let mut vec = $crate::ArrayVec::new();
+        let elem = $elem;
+        for _ in 0..$n {
+            if vec.try_push(::core::clone::Clone::clone(&elem)).is_err() {
+                panic!("array_vec!: more elements than the capacity of {}", vec.capacity());
+            }
+        }
+        vec
+    }};
+
+    ($($elem:expr),* $(,)?) => {{
+        #[allow(unused_mut)]
+        let mut vec = $crate::ArrayVec::new();
+        $(
+            if vec.try_push($elem).is_err() {
+                panic!("array_vec!: more elements than the capacity of {}", vec.capacity());
+            }
+        )*
+        vec
+    }};
+}
+
+/// Creates an [`ArrayString`](crate::ArrayString) with capacity `N` from a format string,
+/// like `format!` but without allocating.
+///
+/// `array_string!(N; "{}-{}", a, b)` returns a `Result<ArrayString<N>, core::fmt::Error>`.
+/// If the formatted text doesn't fit, `Err` is returned;
+/// the pieces that were written before the overflow are discarded along with the string.
+#[macro_export]
+macro_rules! array_string {
+    ($n:expr; $($arg:tt)*) => {{
+        let mut string = $crate::ArrayString::<{ $n }>::default();
+        match ::core::fmt::Write::write_fmt(&mut string, ::core::format_args!($($arg)*)) {
+            ::core::result::Result::Ok(()) => ::core::result::Result::Ok(string),
+            ::core::result::Result::Err(e) => ::core::result::Result::Err(e),
+        }
+    }};
+}
@@ -0,0 +1,93 @@
+use crate::ArrayVec;
+
+use core::{
+    fmt,
+    marker::PhantomData,
+};
+
+use serde::{
+    de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
+    Serialize, Serializer,
+};
+
+/// Serializes the elements of the `ArrayVec` as a sequence,
+/// the same way a `Vec<T>` would be serialized.
+impl <T: Serialize, const N: usize> Serialize for ArrayVec<T, {N}> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer
+    {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+/// Deserializes a sequence into an `ArrayVec`.
+/// Sequences longer than the capacity are rejected with an `invalid_length` error.
+impl <'de, T: Deserialize<'de>, const N: usize> Deserialize<'de> for ArrayVec<T, {N}> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        deserializer.deserialize_seq(ArrayVecVisitor::<T, {N}>(PhantomData))
+    }
+}
+
+struct ArrayVecVisitor<T, const N: usize>(PhantomData<T>);
+
+impl <'de, T: Deserialize<'de>, const N: usize> Visitor<'de> for ArrayVecVisitor<T, {N}> {
+    type Value = ArrayVec<T, {N}>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a sequence of at most {} elements", N)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>
+    {
+        if let Some(len) = seq.size_hint() {
+            if len > N {
+                return Err(de::Error::invalid_length(len, &self));
+            }
+        }
+
+        let mut values = ArrayVec::<T, {N}>::default();
+
+        while let Some(value) = seq.next_element()? {
+            if values.try_push(value).is_err() {
+                return Err(de::Error::invalid_length(N + 1, &self));
+            }
+        }
+
+        Ok(values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{string::ToString, vec, vec::Vec};
+
+    #[test]
+    fn serialize_like_vec() {
+        let mut v = ArrayVec::<u32, {8}>::default();
+        v.extend(vec![1, 2, 3]);
+
+        let json = serde_json::to_string(&v).unwrap();
+
+        assert_eq!(json, "[1,2,3]");
+        assert_eq!(json, serde_json::to_string(&vec![1u32, 2, 3]).unwrap());
+        assert_eq!(serde_json::from_str::<Vec<u32>>(&json).unwrap(), &*v);
+    }
+
+    #[test]
+    fn deserialize_enforces_capacity() {
+        let v: ArrayVec<u32, {5}> = serde_json::from_str("[1,2,3]").unwrap();
+        assert_eq!(&*v, &[1, 2, 3]);
+
+        match serde_json::from_str::<ArrayVec<u32, {2}>>("[1,2,3]") {
+            Err(err) => assert!(err.to_string().contains("at most 2 elements")),
+            Ok(_) => panic!("deserialized 3 elements into a capacity of 2"),
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use crate::ArrayVec;
+
+use core::{cmp::Ordering, ptr};
+
+impl <T, const N: usize> ArrayVec<T, {N}> {
+    /// Sorts the elements with the comparator function `compare`,
+    /// preserving the order of equal elements.
+    ///
+    /// Unlike the slice method, this doesn't allocate, so it is available without the `alloc` feature.
+    /// When the spare capacity is at least the length, it is used as scratch space
+    /// for a merge sort, which is O(n log n). Otherwise this falls back to an insertion sort,
+    /// which is O(n²).
+    ///
+    /// If `compare` panics, the `ArrayVec` holds all of its elements in an unspecified order.
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering
+    {
+        let len = self.len();
+
+        if len < 2 {
+            return;
+        }
+
+        if self.remaining_capacity() < len {
+            insertion_sort(self.as_mut_slice(), &mut compare);
+            return;
+        }
+
+        unsafe {
+            let first: *mut T = self.array.as_mut_ptr() as *mut T;
+            let scratch = first.add(len);
+            let mut width = 1;
+
+            while width < len {
+                let mut start = 0;
+
+                while start + width < len {
+                    let end = core::cmp::min(start + 2 * width, len);
+                    merge(first.add(start), width, end - start, scratch, &mut compare);
+                    start = end;
+                }
+
+                width *= 2;
+            }
+        }
+    }
+}
+
+/// Sorts `v` by swapping each element down into place.
+/// Every step leaves `v` a permutation of itself, so a panicking `compare` can't lose elements.
+fn insertion_sort<T, F>(v: &mut [T], compare: &mut F)
+where
+    F: FnMut(&T, &T) -> Ordering
+{
+    for i in 1..v.len() {
+        let mut j = i;
+
+        while j > 0 && compare(&v[j - 1], &v[j]) == Ordering::Greater {
+            v.swap(j - 1, j);
+            j -= 1;
+        }
+    }
+}
+
+/// Merges the sorted runs `v[..mid]` and `v[mid..len]`.
+/// The first run is moved to `buf`, and merged back into `v` from the front.
+///
+/// # Unsafe
+/// `v` must be valid for `len` elements, and `buf` must be valid for `mid` elements
+/// and not overlap `v`.
+unsafe fn merge<T, F>(v: *mut T, mid: usize, len: usize, buf: *mut T, compare: &mut F)
+where
+    F: FnMut(&T, &T) -> Ordering
+{
+    ptr::copy_nonoverlapping(v, buf, mid);
+
+    // The hole in `v` between `dest` and `right` is exactly as long as the part of
+    // the first run that is not merged back yet. If `compare` panics, the hole is filled with it.
+    let mut hole = MergeHole { v, dest: 0, buf, start: 0, end: mid };
+    let mut right = mid;
+
+    while hole.start < hole.end && right < len {
+        // Take from the second run only if it's strictly less, so equal elements keep their order.
+        let src = if compare(&*v.add(right), &*buf.add(hole.start)) == Ordering::Less {
+            right += 1;
+            v.add(right - 1)
+        } else {
+            hole.start += 1;
+            buf.add(hole.start - 1)
+        };
+
+        ptr::copy_nonoverlapping(src, v.add(hole.dest), 1);
+        hole.dest += 1;
+    }
+}
+
+struct MergeHole<T> {
+    v: *mut T,
+    dest: usize,
+    buf: *mut T,
+    start: usize,
+    end: usize,
+}
+
+impl <T> Drop for MergeHole<T> {
+    fn drop(&mut self) {
+        unsafe {
+            ptr::copy_nonoverlapping(self.buf.add(self.start), self.v.add(self.dest), self.end - self.start);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn records<const N: usize>() -> ArrayVec<(u8, usize), {N}> {
+        [3, 1, 2, 1, 3, 0, 2, 1, 0, 3]
+            .iter()
+            .enumerate()
+            .map(|(index, &key)| (key, index))
+            .collect()
+    }
+
+    fn assert_stable_sorted(v: &[(u8, usize)]) {
+        assert_eq!(v.len(), 10);
+        assert!(v.windows(2).all(|w| w[0] < w[1]), "{:?}", v);
+    }
+
+    #[test]
+    fn sort_by_merge() {
+        let mut v = records::<20>();
+        v.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_stable_sorted(&v);
+    }
+
+    #[test]
+    fn sort_by_insertion() {
+        let mut v = records::<10>();
+        v.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_stable_sorted(&v);
+    }
+
+    #[test]
+    fn sort_by_short() {
+        let mut v: ArrayVec<i32, {8}> = [3, 1, 2].iter().copied().collect();
+        v.sort_by(|a, b| b.cmp(a));
+        assert_eq!(&*v, &[3, 2, 1]);
+
+        let mut v: ArrayVec<i32, {1}> = Some(1).into_iter().collect();
+        v.sort_by(|a, b| a.cmp(b));
+        assert_eq!(&*v, &[1]);
+    }
+
+    #[test]
+    fn sort_by_panic_safety() {
+        use std::{panic::{self, AssertUnwindSafe}, string::{String, ToString}};
+
+        let mut v: ArrayVec<String, {20}> = (0..10).rev().map(|n| n.to_string()).collect();
+        let mut calls = 0;
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            v.sort_by(|a, b| {
+                calls += 1;
+                if calls == 12 {
+                    panic!("boom");
+                }
+                a.cmp(b)
+            })
+        }));
+
+        assert!(result.is_err());
+
+        let mut bytes: ArrayVec<u8, {10}> = v.iter().map(|s| s.as_bytes()[0]).collect();
+        bytes.sort_unstable();
+        assert_eq!(&*bytes, b"0123456789");
+    }
+}
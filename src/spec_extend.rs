@@ -0,0 +1,101 @@
+//! A faster `Extend` for iterators that report their exact length.
+
+use crate::ArrayVec;
+
+use core::{cmp, iter::TrustedLen, ptr};
+
+pub(crate) trait SpecExtend<T, I> {
+    fn spec_extend(&mut self, iter: I);
+}
+
+impl <T, I, const N: usize> SpecExtend<T, I> for ArrayVec<T, {N}>
+where
+    I: Iterator<Item = T>
+{
+    default fn spec_extend(&mut self, iter: I) {
+        self.extend_from_iter(iter)
+    }
+}
+
+/// The number of elements to take is known up front,
+/// so the length is only written back once, when `SetLenOnDrop` is dropped.
+impl <T, I, const N: usize> SpecExtend<T, I> for ArrayVec<T, {N}>
+where
+    I: TrustedLen<Item = T>
+{
+    fn spec_extend(&mut self, iter: I) {
+        let remaining = self.remaining_capacity();
+
+        // An upper bound of `None` means more than `usize::MAX` elements.
+        let take = match iter.size_hint().1 {
+            Some(exact) => cmp::min(exact, remaining),
+            None => remaining,
+        };
+
+        let dst: *mut T = unsafe { (self.array.as_mut_ptr() as *mut T).add(self.len) };
+        let mut guard = SetLenOnDrop::new(&mut self.len);
+
+        // `take` elements never exceed the remaining capacity.
+        iter.take(take).enumerate().for_each(|(offset, item)| unsafe {
+            ptr::write(dst.add(offset), item);
+            guard.local_len += 1;
+        });
+    }
+}
+
+/// Writes the length back when dropped, even if the iterator panics.
+/// Only the length is borrowed, so the elements can be written through a raw pointer meanwhile.
+struct SetLenOnDrop<'a> {
+    len: &'a mut usize,
+    local_len: usize,
+}
+
+impl <'a> SetLenOnDrop<'a> {
+    #[inline]
+    fn new(len: &'a mut usize) -> Self {
+        let local_len = *len;
+        Self { len, local_len }
+    }
+}
+
+impl Drop for SetLenOnDrop<'_> {
+    #[inline]
+    fn drop(&mut self) {
+        *self.len = self.local_len;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ArrayVec;
+    use std::string::{String, ToString};
+
+    #[test]
+    fn extend_trusted_len() {
+        let mut v: ArrayVec<usize, {8}> = (0..3).collect();
+
+        v.extend(3..5);
+        assert_eq!(&*v, &[0, 1, 2, 3, 4]);
+
+        // Saturates at the capacity.
+        v.extend(5..100);
+        assert_eq!(&*v, &[0, 1, 2, 3, 4, 5, 6, 7]);
+
+        v.extend(0..);
+        assert_eq!(v.len(), 8);
+    }
+
+    #[test]
+    fn extend_trusted_len_panic_safety() {
+        use std::panic::{self, AssertUnwindSafe};
+
+        let mut v: ArrayVec<String, {8}> = ArrayVec::new();
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            v.extend((0..5).map(|n| if n == 3 { panic!("boom") } else { n.to_string() }));
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(&*v, &["0", "1", "2"]);
+    }
+}
@@ -1,7 +1,11 @@
 use crate::{error::CapacityError, ArrayVec};
 
 use core::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
     slice,
+    str::{self, FromStr},
 };
 
 pub struct ArrayString<const N: usize> {
@@ -16,6 +20,15 @@ impl <const N: usize> Default for ArrayString<{N}> {
     }
 }
 
+impl <const N: usize> Clone for ArrayString<{N}> {
+    fn clone(&self) -> Self {
+        let mut array = ArrayVec::new();
+        // The bytes of `self` always fit in the same capacity.
+        let _ = array.try_extend_from_slice(&self.array);
+        Self { array }
+    }
+}
+
 impl <const N: usize> ArrayString<{N}> {
     pub const fn is_full(&self) -> bool {
         self.array.is_full()
@@ -37,11 +50,235 @@ impl <const N: usize> ArrayString<{N}> {
         self.try_push(item).unwrap();
     }
 
+    /// Tries to push `item` onto the `ArrayString`.
+    /// A [`CapacityError`] is returned when the UTF-8 encoding
+    /// of `item` doesn't fit in the remaining capacity.
     pub fn try_push(&mut self, item: char) -> Result<(), CapacityError<char>> {
-        unimplemented!()
+        let mut buf = [0; 4];
+        let encoded = item.encode_utf8(&mut buf);
+
+        self.array
+            .try_extend_from_slice(encoded.as_bytes())
+            .map_err(|_| CapacityError::new(item))
+    }
+
+    pub fn push_str(&mut self, s: &str) {
+        self.try_push_str(s).unwrap();
+    }
+
+    /// Tries to push `s` onto the `ArrayString`.
+    /// Either all of `s` is pushed, or nothing is:
+    /// a [`CapacityError`] is returned when `s` doesn't fit in the remaining capacity.
+    pub fn try_push_str<'a>(&mut self, s: &'a str) -> Result<(), CapacityError<&'a str>> {
+        self.array
+            .try_extend_from_slice(s.as_bytes())
+            .map_err(|_| CapacityError::new(s))
+    }
+
+    /// Removes the last `char` and returns it,
+    /// or `None` if the `ArrayString` is empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<char> {
+        let ch = self.as_str().chars().next_back()?;
+        let new_len = self.len() - ch.len_utf8();
+
+        unsafe { self.array.set_len(new_len) }
+        Some(ch)
+    }
+
+    /// Shortens the `ArrayString` to `new_len` bytes.
+    /// Does nothing if `new_len` is greater than the current length.
+    ///
+    /// # Panics
+    /// Panics if `new_len` is not on a `char` boundary.
+    #[inline]
+    pub fn truncate(&mut self, new_len: usize) {
+        if new_len <= self.len() {
+            assert!(self.as_str().is_char_boundary(new_len), "new_len {} is not on a char boundary", new_len);
+            self.array.truncate(new_len);
+        }
+    }
+
+    /// Returns a string slice of the contents of the `ArrayString`.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        unsafe { str::from_utf8_unchecked(&self.array) }
     }
     
     pub unsafe fn as_bytes_mut(&mut self) -> &mut [u8] {
         slice::from_raw_parts_mut(self.array.as_mut_ptr(), self.capacity())
     }
-}
\ No newline at end of file
+}
+
+/// Writing into an `ArrayString` is atomic per call to `write_str`:
+/// if the string doesn't fit, nothing is written and `fmt::Error` is returned.
+/// Note that a single `write!` may call `write_str` multiple times,
+/// so the pieces written before the failing one are kept.
+impl <const N: usize> fmt::Write for ArrayString<{N}> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.try_push_str(s).map_err(|_| fmt::Error)
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.try_push(c).map_err(|_| fmt::Error)
+    }
+}
+
+impl <const N: usize> PartialEq for ArrayString<{N}> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl <const N: usize> Eq for ArrayString<{N}> {}
+
+impl <const N: usize> PartialEq<str> for ArrayString<{N}> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl <'a, const N: usize> PartialEq<&'a str> for ArrayString<{N}> {
+    #[inline]
+    fn eq(&self, other: &&'a str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl <const N: usize> PartialEq<ArrayString<{N}>> for str {
+    #[inline]
+    fn eq(&self, other: &ArrayString<{N}>) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl <const N: usize> PartialEq<ArrayString<{N}>> for &str {
+    #[inline]
+    fn eq(&self, other: &ArrayString<{N}>) -> bool {
+        *self == other.as_str()
+    }
+}
+
+impl <const N: usize> PartialOrd for ArrayString<{N}> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl <const N: usize> Ord for ArrayString<{N}> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+/// Hashes the same way as the string slice of the contents.
+impl <const N: usize> Hash for ArrayString<{N}> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+/// Parses a string slice into an `ArrayString`.
+/// A [`CapacityError`] is returned when the string slice is longer than the capacity.
+impl <const N: usize> FromStr for ArrayString<{N}> {
+    type Err = CapacityError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut string = Self::default();
+        string.try_push_str(s).map_err(|_| CapacityError::new(()))?;
+        Ok(string)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::fmt::Write;
+
+    #[test]
+    fn write_formatted() {
+        let mut s = ArrayString::<{16}>::default();
+
+        let name = "n";
+        write!(s, "{}={}", name, 12345).unwrap();
+        assert_eq!(s.as_str(), "n=12345");
+
+        assert!(write!(s, "{}", 1234567890).is_err());
+        assert_eq!(s.as_str(), "n=12345");
+    }
+
+    #[test]
+    fn from_str() {
+        let s = "hello".parse::<ArrayString<{8}>>().unwrap();
+        assert_eq!(s.as_str(), "hello");
+
+        assert!("hello, world".parse::<ArrayString<{8}>>().is_err());
+    }
+
+    #[test]
+    fn pop_and_truncate() {
+        let mut s = "café!".parse::<ArrayString<{8}>>().unwrap();
+
+        assert_eq!(s.pop(), Some('!'));
+        assert_eq!(s.pop(), Some('é'));
+        assert_eq!(s.as_str(), "caf");
+
+        s.truncate(10);
+        assert_eq!(s.as_str(), "caf");
+
+        s.truncate(1);
+        assert_eq!(s.as_str(), "c");
+
+        assert_eq!(s.pop(), Some('c'));
+        assert_eq!(s.pop(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "not on a char boundary")]
+    fn truncate_mid_char() {
+        let mut s = "é".parse::<ArrayString<{8}>>().unwrap();
+        s.truncate(1);
+    }
+
+    #[test]
+    fn compare_and_hash() {
+        use std::{collections::hash_map::DefaultHasher, string::String};
+
+        fn hash<H: Hash + ?Sized>(value: &H) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let a = "abc".parse::<ArrayString<{8}>>().unwrap();
+        let b = "abd".parse::<ArrayString<{8}>>().unwrap();
+
+        assert!(a == "abc");
+        assert!("abc" == a);
+        assert!(*"abc" == a);
+        assert!(a == *"abc");
+        assert!(a != b);
+
+        assert!(a < b);
+        assert_eq!(a.cmp(&b), String::from("abc").cmp(&String::from("abd")));
+
+        assert_eq!(hash(&a), hash(&String::from("abc")));
+        assert_eq!(hash(&a), hash("abc"));
+    }
+
+    #[test]
+    fn clone() {
+        let mut s = "hello".parse::<ArrayString<{8}>>().unwrap();
+        let cloned = s.clone();
+
+        s.push('!');
+
+        assert!(cloned == "hello");
+        assert!(s == "hello!");
+    }
+}
@@ -0,0 +1,75 @@
+//! Fixtures shared by the tests of all modules.
+
+use crate::ArrayVec;
+
+use core::cell::{Cell, RefCell};
+
+/// Records how often, and in which order, the [`Counted`] elements it hands out are dropped.
+/// Elements are identified by an id below 32.
+#[derive(Default)]
+pub(crate) struct DropCounter {
+    counts: [Cell<usize>; 32],
+    order: RefCell<ArrayVec<usize, {64}>>,
+}
+
+impl DropCounter {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns an element with `id`, that is recorded when dropped.
+    pub(crate) fn counted(&self, id: usize) -> Counted<'_> {
+        Counted { counter: self, id, bomb: false }
+    }
+
+    /// Returns an element with `id`, that is recorded and then panics when dropped.
+    pub(crate) fn bomb(&self, id: usize) -> Counted<'_> {
+        Counted { counter: self, id, bomb: true }
+    }
+
+    /// Returns the number of times the elements with `id` were dropped.
+    pub(crate) fn count(&self, id: usize) -> usize {
+        self.counts[id].get()
+    }
+
+    /// Returns the total number of drops.
+    pub(crate) fn total(&self) -> usize {
+        self.counts.iter().map(Cell::get).sum()
+    }
+
+    /// Returns whether each of the elements with ids `0..n` was dropped exactly once,
+    /// and no other element was dropped.
+    pub(crate) fn all_dropped_once(&self, n: usize) -> bool {
+        self.counts.iter().enumerate().all(|(id, count)| count.get() == (id < n) as usize)
+    }
+
+    /// Returns the ids of the dropped elements, in the order they were dropped.
+    pub(crate) fn order(&self) -> ArrayVec<usize, {64}> {
+        self.order.borrow().clone()
+    }
+
+    /// Forgets all drops recorded so far.
+    pub(crate) fn reset(&self) {
+        self.counts.iter().for_each(|count| count.set(0));
+        self.order.borrow_mut().clear();
+    }
+}
+
+/// An element handed out by a [`DropCounter`].
+pub(crate) struct Counted<'a> {
+    counter: &'a DropCounter,
+    pub(crate) id: usize,
+    bomb: bool,
+}
+
+impl Drop for Counted<'_> {
+    fn drop(&mut self) {
+        let count = &self.counter.counts[self.id];
+        count.set(count.get() + 1);
+        self.counter.order.borrow_mut().push(self.id);
+
+        if self.bomb {
+            panic!("boom");
+        }
+    }
+}
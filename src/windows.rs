@@ -0,0 +1,68 @@
+use crate::ArrayVec;
+
+impl <T, const N: usize> ArrayVec<T, {N}> {
+    /// Returns a [`WindowsMut`] over all overlapping windows of length `size`.
+    ///
+    /// # Panics
+    /// Panics if `size` is 0.
+    #[inline]
+    pub fn windows_mut(&mut self, size: usize) -> WindowsMut<'_, T> {
+        assert!(size != 0, "window size must be non-zero");
+
+        WindowsMut {
+            slice: self,
+            size,
+            index: 0,
+        }
+    }
+}
+
+/// Yields overlapping mutable windows over the elements of an [`ArrayVec`].
+///
+/// This struct is created by [`ArrayVec::windows_mut`].
+///
+/// Since the windows overlap, only one of them can be borrowed at a time.
+/// Therefore this is not an `Iterator`: every window borrows the `WindowsMut`
+/// until it goes out of scope, so use [`WindowsMut::next_window`] with `while let`.
+pub struct WindowsMut<'a, T> {
+    slice: &'a mut [T],
+    size: usize,
+    index: usize,
+}
+
+impl <'a, T> WindowsMut<'a, T> {
+    /// Returns the next window, or `None` when all windows have been yielded.
+    #[inline]
+    pub fn next_window(&mut self) -> Option<&mut [T]> {
+        let start = self.index;
+        let end = start.checked_add(self.size)?;
+
+        if end > self.slice.len() {
+            None
+        } else {
+            self.index += 1;
+            Some(&mut self.slice[start..end])
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn smooth() {
+        let mut v: ArrayVec<i32, {8}> = [0, 8, 0, 8, 0].iter().copied().collect();
+
+        let mut windows = v.windows_mut(2);
+
+        while let Some(window) = windows.next_window() {
+            window[1] = (window[0] + window[1]) / 2;
+        }
+
+        assert_eq!(&*v, &[0, 4, 2, 5, 2]);
+
+        let mut windows = v.windows_mut(6);
+        assert!(windows.next_window().is_none());
+    }
+}
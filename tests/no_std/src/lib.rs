@@ -0,0 +1,49 @@
+//! Uses `arrayvec` from a `no_std` crate, without the `std` and `alloc` features.
+//! This only has to build; on a target without `std`, it fails to if `arrayvec` links to `std` or `alloc`.
+#![no_std]
+#![feature(const_generics)]
+#![cfg_attr(feature = "nightly", feature(const_evaluatable_checked))]
+
+use arrayvec::{ArrayString, ArrayVec};
+
+pub fn collect_sorted(items: &[u32]) -> Option<ArrayVec<u32, {8}>> {
+    let mut v = ArrayVec::new();
+
+    for &item in items {
+        v.try_push(item).ok()?;
+    }
+
+    v.sort();
+    v.dedup();
+    Some(v)
+}
+
+pub fn remove_evens(v: &mut ArrayVec<u32, {8}>) -> u32 {
+    let mut sum = 0;
+    v.retain(|&item| {
+        if item % 2 == 0 {
+            sum += item;
+            false
+        } else {
+            true
+        }
+    });
+    sum
+}
+
+pub fn greet(name: &str) -> ArrayString<{16}> {
+    let mut s = ArrayString::default();
+    let _ = s.try_push_str("hello, ");
+    let _ = s.try_push_str(name);
+    s
+}
+
+#[cfg(feature = "nightly")]
+pub fn join(a: ArrayVec<u32, {4}>, b: ArrayVec<u32, {4}>) -> ArrayVec<u32, {8}> {
+    a.concat(b)
+}
+
+#[cfg(feature = "nightly")]
+pub fn flatten(nested: ArrayVec<ArrayVec<u32, {2}>, {4}>) -> ArrayVec<u32, {8}> {
+    nested.flatten()
+}